@@ -0,0 +1,173 @@
+//! # Accumulators
+//!
+//! This module contains `Accumulator` and `KahanAccumulator`, counters for lifetime totals such as damage dealt or
+//! healing done over a long session.
+//!
+//! An `i32` total overflows after about two billion points, and a plain `f64` sum stops registering small additions
+//! once the total is large enough. `Accumulator` keeps an exact `u128` total that saturates instead of wrapping, and
+//! `KahanAccumulator` keeps an `f64` total with Kahan compensated summation for fractional amounts. Both also count
+//! how many amounts were added, so averages can be reported.
+
+use bevy_ecs::component::Component;
+use serde::{Deserialize, Serialize};
+
+/// An exact integer total that saturates at `u128::MAX`.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::accumulator::Accumulator;
+///
+/// let mut damage_dealt = Accumulator::new();
+/// damage_dealt.add(u64::MAX);
+/// assert_eq!(damage_dealt.add(u64::MAX), 2 * u128::from(u64::MAX));
+/// assert_eq!(damage_dealt.count(), 2);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Accumulator {
+    /// The sum of every amount added.
+    total: u128,
+    /// The number of amounts added.
+    count: u64,
+}
+
+impl Accumulator {
+    /// Create an empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { total: 0, count: 0 }
+    }
+
+    /// Add an amount to the total.
+    ///
+    /// Returns the new total.
+    pub const fn add(&mut self, amount: u64) -> u128 {
+        self.total = self.total.saturating_add(amount as u128);
+        self.count = self.count.saturating_add(1);
+        self.total
+    }
+
+    /// The sum of every amount added.
+    #[must_use]
+    pub const fn total(&self) -> u128 {
+        self.total
+    }
+
+    /// The number of amounts added.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The average amount added, or `None` if nothing has been added.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total as f64 / self.count as f64)
+    }
+
+    /// Add the totals of another accumulator, e.g. to combine per-match totals into a lifetime total.
+    pub const fn merge(&mut self, other: &Self) {
+        self.total = self.total.saturating_add(other.total);
+        self.count = self.count.saturating_add(other.count);
+    }
+
+    /// Reset to empty, returning the total before the reset.
+    pub const fn take(&mut self) -> u128 {
+        let total = self.total;
+        *self = Self::new();
+        total
+    }
+}
+
+/// A floating point total using Kahan compensated summation, so that small amounts still count when the total is
+/// large.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::accumulator::KahanAccumulator;
+///
+/// let mut healing_done = KahanAccumulator::new();
+/// let mut naive = 0.0_f64;
+/// healing_done.add(1e16);
+/// naive += 1e16;
+/// for _ in 0..1_000 {
+///     healing_done.add(1.0);
+///     naive += 1.0;
+/// }
+///
+/// assert_eq!(healing_done.total(), 1e16 + 1_000.0);
+/// assert_eq!(naive, 1e16);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KahanAccumulator {
+    /// The running sum.
+    sum: f64,
+    /// The low-order error lost from `sum`, to be added back with the next amount.
+    compensation: f64,
+    /// The number of amounts added.
+    count: u64,
+}
+
+impl KahanAccumulator {
+    /// Create an empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            sum: 0.0,
+            compensation: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Add an amount to the total. Non-finite amounts are ignored, so one bad value can't poison a lifetime total.
+    ///
+    /// Returns the new total.
+    pub fn add(&mut self, amount: f64) -> f64 {
+        if amount.is_finite() {
+            let corrected = amount - self.compensation;
+            let sum = self.sum + corrected;
+            self.compensation = (sum - self.sum) - corrected;
+            self.sum = sum;
+            self.count = self.count.saturating_add(1);
+        }
+        self.total()
+    }
+
+    /// The sum of every amount added.
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.sum - self.compensation
+    }
+
+    /// The number of amounts added.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The average amount added, or `None` if nothing has been added.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total() / self.count as f64)
+    }
+
+    /// Add the totals of another accumulator, e.g. to combine per-match totals into a lifetime total.
+    pub fn merge(&mut self, other: &Self) {
+        let count = self.count.saturating_add(other.count);
+        self.add(other.sum);
+        self.add(-other.compensation);
+        self.count = count;
+    }
+
+    /// Reset to empty, returning the total before the reset.
+    pub fn take(&mut self) -> f64 {
+        let total = self.total();
+        *self = Self::new();
+        total
+    }
+}
@@ -0,0 +1,253 @@
+//! # Achievements
+//!
+//! This module contains the `Achievements` component, its conditions, and the `AchievementUnlocked` event.
+//!
+//! An `Achievements` component lists achievements for an entity, each with an id and an `AchievementCondition`. The
+//! conditions are checked against the other components on the same entity: its `IntegerAttribute`, `Watermarks`,
+//! `Accumulator`, and `Level`. A condition that needs a component the entity doesn't have is never met.
+//!
+//! Each achievement unlocks once. The `evaluate_achievements` system sends an `AchievementUnlocked` event when it
+//! does, so meta-progression can react without polling.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    system::Query,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{accumulator::Accumulator, IntegerAttribute, Level, Watermarks};
+
+/// What must be true for an achievement to unlock.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::achievements::AchievementCondition;
+///
+/// // Drop to 1 HP or less, then recover to full.
+/// let comeback = AchievementCondition::AllOf(vec![
+///     AchievementCondition::LowWatermarkAtMost(1),
+///     AchievementCondition::AttributeAtLeast(100),
+/// ]);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AchievementCondition {
+    /// The `IntegerAttribute`'s current value is at least this.
+    AttributeAtLeast(i32),
+    /// The `IntegerAttribute`'s current value is at most this.
+    AttributeAtMost(i32),
+    /// The `Watermarks` low watermark is at most this.
+    LowWatermarkAtMost(i32),
+    /// The `Watermarks` high watermark is at least this.
+    HighWatermarkAtLeast(i32),
+    /// The `Accumulator` total is at least this.
+    TotalAtLeast(u128),
+    /// The `Level` is at least this.
+    LevelAtLeast(u32),
+    /// Every condition is met. An empty list is always met.
+    AllOf(Vec<Self>),
+    /// At least one condition is met. An empty list is never met.
+    AnyOf(Vec<Self>),
+}
+
+/// The components of an entity that conditions are checked against.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AchievementContext<'a> {
+    /// The entity's attribute.
+    pub attribute: Option<&'a IntegerAttribute>,
+    /// The entity's watermarks.
+    pub watermarks: Option<&'a Watermarks>,
+    /// The entity's accumulator.
+    pub accumulator: Option<&'a Accumulator>,
+    /// The entity's level.
+    pub level: Option<&'a Level>,
+}
+
+impl AchievementCondition {
+    /// Check if the condition is met.
+    #[must_use]
+    pub fn is_met(&self, context: &AchievementContext) -> bool {
+        match self {
+            Self::AttributeAtLeast(value) => context
+                .attribute
+                .is_some_and(|attribute| attribute.current_value() >= *value),
+            Self::AttributeAtMost(value) => context
+                .attribute
+                .is_some_and(|attribute| attribute.current_value() <= *value),
+            Self::LowWatermarkAtMost(value) => context
+                .watermarks
+                .is_some_and(|watermarks| watermarks.low_watermark() <= *value),
+            Self::HighWatermarkAtLeast(value) => context
+                .watermarks
+                .is_some_and(|watermarks| watermarks.high_watermark() >= *value),
+            Self::TotalAtLeast(value) => context
+                .accumulator
+                .is_some_and(|accumulator| accumulator.total() >= *value),
+            Self::LevelAtLeast(value) => context.level.is_some_and(|level| level.level >= *value),
+            Self::AllOf(conditions) => conditions.iter().all(|condition| condition.is_met(context)),
+            Self::AnyOf(conditions) => conditions.iter().any(|condition| condition.is_met(context)),
+        }
+    }
+}
+
+/// An achievement and whether it has been unlocked.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Achievement {
+    /// The id of the achievement.
+    pub id: String,
+    /// What must be true for it to unlock.
+    pub condition: AchievementCondition,
+    /// Whether it has been unlocked.
+    pub unlocked: bool,
+}
+
+/// An event sent when an achievement unlocks.
+#[derive(Event, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AchievementUnlocked {
+    /// The entity that unlocked the achievement.
+    pub entity: Entity,
+    /// The id of the achievement.
+    pub id: String,
+}
+
+/// The achievements an entity can unlock.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     achievements::{AchievementCondition, AchievementContext, Achievements},
+///     IntegerAttribute, Watermarks,
+/// };
+///
+/// let mut achievements = Achievements::new()
+///     .with("close_call", AchievementCondition::LowWatermarkAtMost(1));
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut watermarks = Watermarks::new(&health);
+/// health -= 99;
+/// watermarks.observe(&health);
+///
+/// let context = AchievementContext {
+///     watermarks: Some(&watermarks),
+///     ..Default::default()
+/// };
+/// assert_eq!(achievements.evaluate(&context), vec!["close_call".to_string()]);
+/// assert!(achievements.evaluate(&context).is_empty());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Achievements {
+    /// The achievements, in the order they were added.
+    achievements: Vec<Achievement>,
+}
+
+impl Achievements {
+    /// Create an empty list of achievements.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            achievements: Vec::new(),
+        }
+    }
+
+    /// Add a locked achievement.
+    #[must_use]
+    pub fn with(mut self, id: impl Into<String>, condition: AchievementCondition) -> Self {
+        self.add(id, condition);
+        self
+    }
+
+    /// Add a locked achievement.
+    pub fn add(&mut self, id: impl Into<String>, condition: AchievementCondition) {
+        self.achievements.push(Achievement {
+            id: id.into(),
+            condition,
+            unlocked: false,
+        });
+    }
+
+    /// Iterate over the achievements, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Achievement> {
+        self.achievements.iter()
+    }
+
+    /// Check if an achievement has been unlocked. Unknown ids are not unlocked.
+    #[must_use]
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.achievements
+            .iter()
+            .any(|achievement| achievement.id == id && achievement.unlocked)
+    }
+
+    /// Check if every achievement has been unlocked.
+    #[must_use]
+    pub fn all_unlocked(&self) -> bool {
+        self.achievements
+            .iter()
+            .all(|achievement| achievement.unlocked)
+    }
+
+    /// Check every locked achievement, unlocking the ones whose conditions are met.
+    ///
+    /// Returns the ids of the achievements unlocked by this call.
+    pub fn evaluate(&mut self, context: &AchievementContext) -> Vec<String> {
+        self.achievements
+            .iter_mut()
+            .filter(|achievement| !achievement.unlocked && achievement.condition.is_met(context))
+            .map(|achievement| {
+                achievement.unlocked = true;
+                achievement.id.clone()
+            })
+            .collect()
+    }
+}
+
+/// The entities with achievements, and the components their conditions are checked against.
+type AchievementQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Achievements,
+        Option<&'static IntegerAttribute>,
+        Option<&'static Watermarks>,
+        Option<&'static Accumulator>,
+        Option<&'static Level>,
+    ),
+>;
+
+/// Check the locked achievements of every entity, sending an `AchievementUnlocked` event for each that unlocks.
+pub fn evaluate_achievements(
+    mut query: AchievementQuery,
+    mut events: EventWriter<AchievementUnlocked>,
+) {
+    for (entity, mut achievements, attribute, watermarks, accumulator, level) in &mut query {
+        if achievements.all_unlocked() {
+            continue;
+        }
+        let context = AchievementContext {
+            attribute,
+            watermarks,
+            accumulator,
+            level,
+        };
+        // Check before evaluating, so change detection only fires when something unlocks.
+        if !achievements
+            .iter()
+            .any(|achievement| !achievement.unlocked && achievement.condition.is_met(&context))
+        {
+            continue;
+        }
+        events.send_batch(
+            achievements
+                .evaluate(&context)
+                .into_iter()
+                .map(|id| AchievementUnlocked { entity, id }),
+        );
+    }
+}
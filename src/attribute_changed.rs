@@ -0,0 +1,77 @@
+//! # Attribute Changed
+//!
+//! This module contains the `AttributeChanged` event and the system that emits it.
+//!
+//! Add an `AttributeChangeTracker` next to an `IntegerAttribute` to opt in. The `emit_attribute_changes` system uses
+//! Bevy change detection to find attributes that were touched, compares their current value with the last value the
+//! tracker saw, and sends an `AttributeChanged` event when it differs. Health bars and audio cues can read the events
+//! instead of polling every attribute each frame.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    query::Changed,
+    system::Query,
+};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// An event sent when the current value of a tracked `IntegerAttribute` changes.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeChanged {
+    /// The entity that owns the attribute.
+    pub entity: Entity,
+    /// The value the last time it was checked.
+    pub old: i32,
+    /// The value now.
+    pub new: i32,
+}
+
+/// Marks an `IntegerAttribute` for change events and remembers its last seen value.
+///
+/// The first time the attribute is checked its value is recorded without sending an event.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+pub struct AttributeChangeTracker {
+    /// The last value seen by `emit_attribute_changes`.
+    last: Option<i32>,
+}
+
+impl AttributeChangeTracker {
+    /// Create a tracker that hasn't seen a value yet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// The last value seen, if the attribute has been checked.
+    #[must_use]
+    pub const fn last(&self) -> Option<i32> {
+        self.last
+    }
+
+    /// Record the current value of the attribute.
+    ///
+    /// Returns the previous value if it differs from the new one.
+    pub fn observe(&mut self, attribute: &IntegerAttribute) -> Option<i32> {
+        let new = attribute.current_value();
+        self.last.replace(new).filter(|old| *old != new)
+    }
+}
+
+/// Send an `AttributeChanged` event for every tracked attribute `T` whose value changed since it was last checked.
+pub fn emit_attribute_changes<T: AttributeComponent>(
+    mut query: Query<(Entity, &T, &mut AttributeChangeTracker), Changed<T>>,
+    mut events: EventWriter<AttributeChanged>,
+) {
+    for (entity, attribute, mut tracker) in &mut query {
+        let attribute = attribute.attribute();
+        if let Some(old) = tracker.observe(attribute) {
+            events.send(AttributeChanged {
+                entity,
+                old,
+                new: attribute.current_value(),
+            });
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! # Bounded Attribute
+//!
+//! This module contains the `BoundedAttribute` component, which pairs an `IntegerAttribute` with an `OverflowPolicy`.
+//!
+//! The operators on an `IntegerAttribute` always clamp silently, which hides mistakes such as overhealing or spending
+//! mana that isn't there. A `BoundedAttribute` decides once per attribute what happens to a change that doesn't fit:
+//! it can clamp as usual, reject the change with an error, or wrap around.
+
+use bevy_ecs::component::Component;
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeError, IntegerAttribute, OverflowPolicy};
+
+/// An `IntegerAttribute` whose out-of-range changes are handled by an `OverflowPolicy`.
+///
+/// `set_value` and `add_value` report rejected changes. The arithmetic operators can't return an error, so with
+/// `OverflowPolicy::Error` they leave the attribute unchanged instead.
+///
+/// Changes made directly to `attribute` bypass the policy.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{BoundedAttribute, OverflowPolicy, IntegerAttribute};
+///
+/// let mut mana = BoundedAttribute::new(IntegerAttribute::new(10), OverflowPolicy::Error);
+/// assert_eq!(mana.add_value(-8), Ok(2));
+/// assert!(mana.add_value(-8).is_err());
+/// assert_eq!(mana.current_value(), 2);
+///
+/// let mut hour = BoundedAttribute::new(
+///     IntegerAttribute::new_as_defined(0, 23, 22).expect("Invalid attribute"),
+///     OverflowPolicy::WrapAround,
+/// );
+/// hour += 3;
+/// assert_eq!(hour.current_value(), 1);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BoundedAttribute {
+    /// The attribute being bounded.
+    pub attribute: IntegerAttribute,
+    /// What happens to a change that would take the attribute outside of `min` and `max`.
+    pub policy: OverflowPolicy,
+}
+
+impl BoundedAttribute {
+    /// Bound an attribute with the given policy.
+    #[must_use]
+    pub const fn new(attribute: IntegerAttribute, policy: OverflowPolicy) -> Self {
+        Self { attribute, policy }
+    }
+
+    /// Get the current value of the attribute.
+    #[must_use]
+    pub const fn current_value(&self) -> i32 {
+        self.attribute.current_value()
+    }
+
+    /// Set the current value, handling a value outside of `min` and `max` according to the policy.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the policy is `OverflowPolicy::Error` and the value is outside
+    /// of `min` and `max`. The attribute is left unchanged.
+    pub fn set_value(&mut self, value: i32) -> Result<i32, AttributeError> {
+        self.apply(i64::from(value))
+    }
+
+    /// Add to the current value, handling a result outside of `min` and `max` according to the policy.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the policy is `OverflowPolicy::Error` and the result would be
+    /// outside of `min` and `max`. The attribute is left unchanged.
+    pub fn add_value(&mut self, rhs: i32) -> Result<i32, AttributeError> {
+        self.apply(i64::from(self.attribute.current) + i64::from(rhs))
+    }
+
+    /// Set the current value to the result of some arithmetic, done in `i64` so that it can't overflow.
+    fn apply(&mut self, value: i64) -> Result<i32, AttributeError> {
+        let (min, max) = (i64::from(self.attribute.min), i64::from(self.attribute.max));
+        let value = match self.policy {
+            OverflowPolicy::Clamp | OverflowPolicy::Saturate => value.clamp(min, max),
+            OverflowPolicy::Error if value < min || value > max => {
+                return Err(AttributeError::OutOfBounds(value, min, max));
+            }
+            OverflowPolicy::Error => value,
+            OverflowPolicy::WrapAround => i64::from(self.attribute.wrap(value)),
+        };
+        // The value is between two `i32`s, so it fits.
+        let current =
+            i32::try_from(value).map_err(|_| AttributeError::OutOfBounds(value, min, max))?;
+        self.attribute.set_value(current);
+        Ok(current)
+    }
+}
+
+impl From<IntegerAttribute> for BoundedAttribute {
+    /// Bound an attribute with the default policy, `OverflowPolicy::Clamp`.
+    fn from(attribute: IntegerAttribute) -> Self {
+        Self::new(attribute, OverflowPolicy::default())
+    }
+}
+
+impl std::ops::AddAssign<i32> for BoundedAttribute {
+    /// Add to the current value. See `add_value`.
+    fn add_assign(&mut self, rhs: i32) {
+        let _ = self.add_value(rhs);
+    }
+}
+
+impl std::ops::SubAssign<i32> for BoundedAttribute {
+    /// Subtract from the current value according to the policy.
+    fn sub_assign(&mut self, rhs: i32) {
+        let _ = self.apply(i64::from(self.attribute.current) - i64::from(rhs));
+    }
+}
+
+impl std::ops::MulAssign<i32> for BoundedAttribute {
+    /// Multiply the current value according to the policy.
+    fn mul_assign(&mut self, rhs: i32) {
+        let _ = self.apply(i64::from(self.attribute.current) * i64::from(rhs));
+    }
+}
+
+impl std::ops::DivAssign<i32> for BoundedAttribute {
+    /// Divide the current value according to the policy.
+    ///
+    /// Panics if `rhs` is 0, like integer division.
+    fn div_assign(&mut self, rhs: i32) {
+        let _ = self.apply(i64::from(self.attribute.current) / i64::from(rhs));
+    }
+}
@@ -0,0 +1,32 @@
+//! # Compatibility
+//!
+//! This module re-exports the public paths of earlier releases, so that games built against them can upgrade first
+//! and move to the new paths one import at a time.
+//!
+//! Each release gets its own module. An item that has moved is re-exported here from its new home, and renamed or
+//! replaced functions stay on their types as `#[deprecated]` shims for at least one release, pointing at what replaced
+//! them.
+//!
+//! ```rust
+//! #![allow(deprecated)]
+//! use nwest_shared_component_library::compat::v0_1::IntegerAttribute;
+//!
+//! let mana = IntegerAttribute::with_min_max_and_current(0, 10, 5).expect("Invalid attribute");
+//! assert_eq!(mana, 5);
+//! ```
+
+/// The public paths of 0.1.
+pub mod v0_1 {
+    pub use crate::errors::AttributeError;
+    pub use crate::integer_attribute::IntegerAttribute;
+
+    /// The `errors` module of 0.1.
+    pub mod errors {
+        pub use crate::errors::AttributeError;
+    }
+
+    /// The `integer_attribute` module of 0.1.
+    pub mod integer_attribute {
+        pub use crate::integer_attribute::IntegerAttribute;
+    }
+}
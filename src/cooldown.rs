@@ -0,0 +1,214 @@
+//! # Cooldown
+//!
+//! This module contains the implementation of the `Cooldown` component and the system that advances it.
+//!
+//! A `Cooldown` gates an ability behind a recharge time. It holds one or more charges: triggering spends a charge,
+//! and spent charges come back one at a time, each taking `duration` seconds. A single-charge cooldown behaves like a
+//! classic ability cooldown.
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::AttributeError;
+
+/// A recharge timer with one or more charges.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::Cooldown;
+///
+/// // A dash with two charges, each taking 3 seconds to come back.
+/// let mut dash = Cooldown::new(3.0).with_charges(2);
+///
+/// assert!(dash.trigger());
+/// assert!(dash.trigger());
+/// assert!(!dash.is_ready());
+///
+/// dash.tick(3.0);
+/// assert_eq!(dash.charges(), 1);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "CooldownFields")]
+pub struct Cooldown {
+    /// How long, in seconds, it takes one charge to come back.
+    pub duration: f32,
+    /// Seconds left until the next charge comes back. Zero while all charges are available.
+    remaining: f32,
+    /// The most charges that can be held.
+    max_charges: u32,
+    /// The charges available now.
+    charges: u32,
+}
+
+/// The serialized fields of a `Cooldown`, checked when deserializing.
+#[derive(Deserialize)]
+struct CooldownFields {
+    duration: f32,
+    remaining: f32,
+    max_charges: u32,
+    charges: u32,
+}
+
+impl TryFrom<CooldownFields> for Cooldown {
+    type Error = AttributeError;
+
+    fn try_from(fields: CooldownFields) -> Result<Self, Self::Error> {
+        for (name, seconds) in [
+            ("duration", fields.duration),
+            ("remaining", fields.remaining),
+        ] {
+            if !seconds.is_finite() || seconds < 0.0 {
+                return Err(AttributeError::ConversionError(format!(
+                    "Cooldown {name} must be a finite, non-negative number of seconds. {seconds}"
+                )));
+            }
+        }
+        if fields.max_charges == 0 || fields.charges > fields.max_charges {
+            return Err(AttributeError::ConversionError(format!(
+                "Cooldown charges must be between 0 and a maximum of at least 1. {} of {}",
+                fields.charges, fields.max_charges
+            )));
+        }
+
+        Ok(Self {
+            duration: fields.duration,
+            remaining: fields.remaining,
+            max_charges: fields.max_charges,
+            charges: fields.charges,
+        })
+    }
+}
+
+impl Cooldown {
+    /// Create a ready cooldown with a single charge that takes `duration` seconds to come back.
+    #[must_use]
+    pub const fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            remaining: 0.0,
+            max_charges: 1,
+            charges: 1,
+        }
+    }
+
+    /// Set the most charges that can be held, refilling all of them. At least one charge is always allowed.
+    #[must_use]
+    pub const fn with_charges(mut self, max_charges: u32) -> Self {
+        self.max_charges = if max_charges == 0 { 1 } else { max_charges };
+        self.charges = self.max_charges;
+        self.remaining = 0.0;
+        self
+    }
+
+    /// Check if a charge is available.
+    #[must_use]
+    pub const fn is_ready(&self) -> bool {
+        self.charges > 0
+    }
+
+    /// The charges available now.
+    #[must_use]
+    pub const fn charges(&self) -> u32 {
+        self.charges
+    }
+
+    /// The most charges that can be held.
+    #[must_use]
+    pub const fn max_charges(&self) -> u32 {
+        self.max_charges
+    }
+
+    /// Seconds left until the next charge comes back. Zero while all charges are available.
+    #[must_use]
+    pub const fn remaining(&self) -> f32 {
+        self.remaining
+    }
+
+    /// How far the next charge is through recharging, from 0.0 to 1.0. Full cooldowns report 1.0.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        if self.charges == self.max_charges || self.duration <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - self.remaining / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Spend a charge, if one is available.
+    ///
+    /// Returns `true` if a charge was spent.
+    pub const fn trigger(&mut self) -> bool {
+        if self.charges == 0 {
+            return false;
+        }
+
+        // Start recharging unless a charge is already on its way back.
+        if self.charges == self.max_charges {
+            self.remaining = self.duration;
+        }
+        self.charges -= 1;
+        true
+    }
+
+    /// Refill every charge immediately.
+    pub const fn reset(&mut self) {
+        self.charges = self.max_charges;
+        self.remaining = 0.0;
+    }
+
+    /// Advance the recharge by `delta_seconds`, bringing back every charge that finishes.
+    ///
+    /// Returns the number of charges that came back.
+    pub fn tick(&mut self, delta_seconds: f32) -> u32 {
+        self.simulate_elapsed(f64::from(delta_seconds))
+    }
+
+    /// Fast-forward the recharge by `seconds`, such as the time a game was closed, in a single step.
+    ///
+    /// This is `tick` computed in `f64`. Negative or non-finite durations are ignored. Returns the number of charges
+    /// that came back.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn simulate_elapsed(&mut self, seconds: f64) -> u32 {
+        let missing = self.max_charges - self.charges;
+        if missing == 0 || !seconds.is_finite() || seconds < 0.0 {
+            return 0;
+        }
+
+        let remaining = f64::from(self.remaining) - seconds;
+        if remaining > 0.0 {
+            self.remaining = remaining as f32;
+            return 0;
+        }
+
+        let duration = f64::from(self.duration);
+        let recovered = if duration <= 0.0 {
+            missing
+        } else {
+            // One charge for reaching zero, plus one for every full duration past it. Float to integer casts
+            // saturate, so a huge duration is handled.
+            let extra = (-remaining / duration).floor() as u32;
+            extra.saturating_add(1).min(missing)
+        };
+
+        self.charges += recovered;
+        self.remaining = if self.charges == self.max_charges {
+            0.0
+        } else {
+            f64::from(recovered).mul_add(duration, remaining) as f32
+        };
+        recovered
+    }
+}
+
+/// Advance every `Cooldown` by the piped-in delta time (in seconds).
+pub fn tick_cooldowns(In(delta_seconds): In<f32>, mut query: Query<&mut Cooldown>) {
+    for mut cooldown in &mut query {
+        if cooldown.charges < cooldown.max_charges {
+            cooldown.tick(delta_seconds);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! # Scaling Curves
+//!
+//! This module contains the `ScalingCurve` enum, which derives a stat's value from a level.
+//!
+//! Where a `LevelCurve` decides how much experience a level costs, a `ScalingCurve` decides what a level is worth:
+//! the maximum health of a level 10 goblin, or the damage of a rank 3 spell. Levels start at 1, so every curve's
+//! `base` is its value at level 1.
+
+use serde::{Deserialize, Serialize};
+
+use crate::IntegerAttribute;
+
+/// A point on a `ScalingCurve::Table`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CurvePoint {
+    /// The level of the point.
+    pub level: u32,
+    /// The value at that level.
+    pub value: f64,
+}
+
+/// A curve mapping a level to a value.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::curves::ScalingCurve;
+///
+/// let health = ScalingCurve::Linear { base: 50.0, per_level: 12.5 };
+/// assert_eq!(health.evaluate(1), 50.0);
+/// assert_eq!(health.evaluate(5), 100.0);
+///
+/// let goblin = health.attribute_at(9);
+/// assert_eq!(goblin.max, 150);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ScalingCurve {
+    /// Grows by a fixed amount each level: `base + per_level * (level - 1)`.
+    Linear {
+        /// The value at level 1.
+        base: f64,
+        /// The amount added for each level after the first.
+        per_level: f64,
+    },
+    /// Grows by a fixed factor each level: `base * factor^(level - 1)`.
+    Exponential {
+        /// The value at level 1.
+        base: f64,
+        /// The factor applied for each level after the first.
+        factor: f64,
+    },
+    /// An S-curve from `min` to `max`, growing fastest around `midpoint`. Useful for stats that should level off.
+    Logistic {
+        /// The value approached at low levels.
+        min: f64,
+        /// The value approached at high levels.
+        max: f64,
+        /// The level at which the value is halfway between `min` and `max`.
+        midpoint: f64,
+        /// How quickly the value moves from `min` to `max` around the midpoint.
+        steepness: f64,
+    },
+    /// Hand-tuned values, linearly interpolated between points.
+    ///
+    /// Levels before the first point or after the last use that point's value. The points don't need to be sorted.
+    /// An empty table is 0 at every level.
+    Table(Vec<CurvePoint>),
+}
+
+impl ScalingCurve {
+    /// The value at `level`. Level 0 is treated as level 1.
+    #[must_use]
+    pub fn evaluate(&self, level: u32) -> f64 {
+        let level = level.max(1);
+        let steps = f64::from(level - 1);
+        match self {
+            Self::Linear { base, per_level } => per_level.mul_add(steps, *base),
+            Self::Exponential { base, factor } => base * factor.powf(steps),
+            Self::Logistic {
+                min,
+                max,
+                midpoint,
+                steepness,
+            } => min + (max - min) / (1.0 + (-steepness * (f64::from(level) - midpoint)).exp()),
+            Self::Table(points) => Self::interpolate(points, level),
+        }
+    }
+
+    /// The value at `level`, rounded to the nearest integer and saturated to the `i32` range.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn evaluate_rounded(&self, level: u32) -> i32 {
+        // Float to integer casts saturate, and NaN becomes 0.
+        self.evaluate(level).round() as i32
+    }
+
+    /// A full `IntegerAttribute` whose maximum is the value at `level`.
+    #[must_use]
+    pub fn attribute_at(&self, level: u32) -> IntegerAttribute {
+        IntegerAttribute::new(self.evaluate_rounded(level))
+    }
+
+    /// Linearly interpolate a table at `level`.
+    fn interpolate(points: &[CurvePoint], level: u32) -> f64 {
+        let below = points
+            .iter()
+            .filter(|point| point.level <= level)
+            .max_by_key(|point| point.level);
+        let above = points
+            .iter()
+            .filter(|point| point.level >= level)
+            .min_by_key(|point| point.level);
+
+        match (below, above) {
+            (Some(below), Some(above)) if above.level > below.level => {
+                let t = f64::from(level - below.level) / f64::from(above.level - below.level);
+                (above.value - below.value).mul_add(t, below.value)
+            }
+            (Some(point), _) | (None, Some(point)) => point.value,
+            (None, None) => 0.0,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! # Depletion
+//!
+//! This module contains the `Depletable` component, the `Depleted` marker, the `AttributeDepleted` event, and the
+//! system that maintains them.
+//!
+//! Depletion is opt-in: only entities with both an attribute, such as an `IntegerAttribute` or `Health`, and a
+//! `Depletable` are tracked. When the attribute
+//! reaches its minimum, the entity gets a `Depleted` marker and an `AttributeDepleted` event is sent, which covers
+//! death, exhaustion, and broken equipment without every game writing the same check.
+//!
+//! The `DepletionPolicy` decides what happens afterwards. A `Modifiable` attribute can be restored, which removes the
+//! marker. A `Locked` attribute stays at its minimum: any change is undone the next time the system runs, until the
+//! game removes the `Depleted` marker itself. Reviving an entity with a `ReviveRequest` removes the marker, so a revive
+//! sticks even though `update_depletion` runs right after it.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    query::{Changed, Has},
+    system::{Commands, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::AttributeComponent;
+
+/// What happens to an attribute after it is depleted.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DepletionPolicy {
+    /// The attribute can still change. Raising it above its minimum removes the `Depleted` marker.
+    #[default]
+    Modifiable,
+    /// The attribute is held at its minimum until the `Depleted` marker is removed.
+    Locked,
+}
+
+/// Opts an entity's attribute in to depletion tracking.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+/// use nwest_shared_component_library::{
+///     depletion::{update_depletion, AttributeDepleted, Depletable, Depleted, DepletionPolicy},
+///     IntegerAttribute,
+/// };
+///
+/// let mut world = World::new();
+/// world.init_resource::<Events<AttributeDepleted>>();
+/// let mut health = IntegerAttribute::new(100);
+/// health -= 100;
+/// let entity = world.spawn((health, Depletable::new(DepletionPolicy::Locked))).id();
+///
+/// world.run_system_once(update_depletion::<IntegerAttribute>);
+/// assert!(world.get::<Depleted>(entity).is_some());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Depletable {
+    /// What happens to the attribute after it is depleted.
+    pub policy: DepletionPolicy,
+}
+
+impl Depletable {
+    /// Track depletion with the given policy.
+    #[must_use]
+    pub const fn new(policy: DepletionPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+/// Present while a `Depletable` entity's attribute is depleted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+pub struct Depleted;
+
+/// An event sent when a `Depletable` entity's attribute reaches its minimum.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeDepleted {
+    /// The entity whose attribute was depleted.
+    pub entity: Entity,
+}
+
+/// The depletable attributes that changed, and whether they are already depleted.
+type DepletionQuery<'w, 's, T> =
+    Query<'w, 's, (Entity, &'static mut T, &'static Depletable, Has<Depleted>), Changed<T>>;
+
+/// Insert or remove `Depleted` on every `Depletable` entity whose attribute `T` changed.
+///
+/// Sends an `AttributeDepleted` event for each entity that became depleted, and holds `Locked` attributes at their
+/// minimum.
+pub fn update_depletion<T: AttributeComponent>(
+    mut commands: Commands,
+    mut query: DepletionQuery<T>,
+    mut events: EventWriter<AttributeDepleted>,
+) {
+    for (entity, mut attribute, depletable, depleted) in &mut query {
+        let empty = attribute.attribute().current_value() == attribute.attribute().min;
+        match (empty, depleted, depletable.policy) {
+            (true, false, _) => {
+                commands.entity(entity).insert(Depleted);
+                events.send(AttributeDepleted { entity });
+            }
+            (false, true, DepletionPolicy::Modifiable) => {
+                commands.entity(entity).remove::<Depleted>();
+            }
+            (false, true, DepletionPolicy::Locked) => {
+                let attribute = attribute.attribute_mut();
+                attribute.set_value(attribute.min);
+            }
+            _ => {}
+        }
+    }
+}
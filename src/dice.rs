@@ -0,0 +1,205 @@
+//! # Dice
+//!
+//! This module contains the `Roll` type, which parses tabletop dice notation such as `3d6+2` and rolls it.
+//!
+//! It is only available with the `dice` feature. Rolling takes any `rand::Rng`, so a seeded generator gives
+//! reproducible character generation. Rolls can fill an `IntegerAttribute` directly, and have advantage and
+//! disadvantage helpers.
+
+use std::str::FromStr;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeError, IntegerAttribute};
+
+/// A dice expression: `count` dice with `sides` sides each, plus a flat `modifier`.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::dice::Roll;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let roll: Roll = "3d6+2".parse().expect("Invalid dice");
+/// assert_eq!(roll.min(), 5);
+/// assert_eq!(roll.max(), 20);
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let strength = roll.roll(&mut rng);
+/// assert!((5..=20).contains(&strength));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "RollFields")]
+pub struct Roll {
+    /// How many dice to roll. At most `Roll::MAX_COUNT`.
+    count: u32,
+    /// How many sides each die has. Always at least 1.
+    sides: u32,
+    /// A flat amount added to the total.
+    modifier: i32,
+}
+
+/// The serialized fields of a `Roll`, checked by `Roll::new` when deserializing.
+#[derive(Deserialize)]
+struct RollFields {
+    count: u32,
+    sides: u32,
+    modifier: i32,
+}
+
+impl Roll {
+    /// The most dice a single roll can have, so that rolling stays cheap.
+    pub const MAX_COUNT: u32 = 1_000;
+
+    /// Create a new roll of `count` dice with `sides` sides, plus `modifier`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sides` is 0, or if `count` is more than `Roll::MAX_COUNT`.
+    pub fn new(count: u32, sides: u32, modifier: i32) -> Result<Self, AttributeError> {
+        if sides == 0 {
+            return Err(AttributeError::ConversionError(
+                "Dice must have at least one side.".to_string(),
+            ));
+        }
+        if count > Self::MAX_COUNT {
+            return Err(AttributeError::ConversionError(format!(
+                "Cannot roll more than {} dice.",
+                Self::MAX_COUNT
+            )));
+        }
+
+        Ok(Self {
+            count,
+            sides,
+            modifier,
+        })
+    }
+
+    /// How many dice are rolled.
+    #[must_use]
+    pub const fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// How many sides each die has.
+    #[must_use]
+    pub const fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    /// The flat amount added to the total.
+    #[must_use]
+    pub const fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// The lowest possible total.
+    #[must_use]
+    pub fn min(&self) -> i32 {
+        Self::total(i64::from(self.count), self.modifier)
+    }
+
+    /// The highest possible total.
+    #[must_use]
+    pub fn max(&self) -> i32 {
+        Self::total(
+            i64::from(self.count).saturating_mul(i64::from(self.sides)),
+            self.modifier,
+        )
+    }
+
+    /// Roll the dice and add the modifier.
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let dice: i64 = (0..self.count)
+            .map(|_| i64::from(rng.gen_range(1..=self.sides)))
+            .sum();
+        Self::total(dice, self.modifier)
+    }
+
+    /// Roll twice and keep the higher total.
+    pub fn roll_with_advantage<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        self.roll(rng).max(self.roll(rng))
+    }
+
+    /// Roll twice and keep the lower total.
+    pub fn roll_with_disadvantage<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        self.roll(rng).min(self.roll(rng))
+    }
+
+    /// Roll a new attribute whose maximum and current value are the total, such as rolled hit points.
+    ///
+    /// The minimum is 0, unless the total is negative.
+    pub fn roll_attribute<R: Rng + ?Sized>(&self, rng: &mut R) -> IntegerAttribute {
+        IntegerAttribute::new(self.roll(rng))
+    }
+
+    /// Roll a new attribute ranging from this roll's `min` to `max`, with the total as its current value.
+    pub fn roll_ranged_attribute<R: Rng + ?Sized>(&self, rng: &mut R) -> IntegerAttribute {
+        IntegerAttribute {
+            min: self.min(),
+            max: self.max(),
+            current: self.roll(rng),
+        }
+    }
+
+    /// Add a modifier to a dice total, saturating at the limits of an `i32`.
+    fn total(dice: i64, modifier: i32) -> i32 {
+        let total = dice.saturating_add(i64::from(modifier));
+        i32::try_from(total).unwrap_or(if total < 0 { i32::MIN } else { i32::MAX })
+    }
+}
+
+impl TryFrom<RollFields> for Roll {
+    type Error = AttributeError;
+
+    fn try_from(fields: RollFields) -> Result<Self, Self::Error> {
+        Self::new(fields.count, fields.sides, fields.modifier)
+    }
+}
+
+impl FromStr for Roll {
+    type Err = AttributeError;
+
+    /// Parse dice notation: `NdS`, `NdS+M`, or `NdS-M`. The count defaults to 1 when left out (`d20`).
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || AttributeError::ConversionError(format!("Invalid dice notation '{value}'."));
+        let notation: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        let (count, rest) = notation.split_once('d').ok_or_else(invalid)?;
+        let count = if count.is_empty() {
+            1
+        } else {
+            count.parse().map_err(|_| invalid())?
+        };
+
+        let (sides, modifier) = match rest.find(['+', '-']) {
+            Some(index) => {
+                let (sides, modifier) = rest.split_at(index);
+                (sides, modifier.parse().map_err(|_| invalid())?)
+            }
+            None => (rest, 0),
+        };
+        let sides = sides.parse().map_err(|_| invalid())?;
+
+        Self::new(count, sides, modifier)
+    }
+}
+
+impl std::fmt::Display for Roll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+        match self.modifier.cmp(&0) {
+            std::cmp::Ordering::Greater => write!(f, "+{}", self.modifier),
+            std::cmp::Ordering::Less => write!(f, "{}", self.modifier),
+            std::cmp::Ordering::Equal => Ok(()),
+        }
+    }
+}
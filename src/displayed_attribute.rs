@@ -0,0 +1,169 @@
+//! # Displayed Attribute
+//!
+//! This module contains the implementation of the `DisplayedAttribute` component and the system that animates it.
+//!
+//! A `DisplayedAttribute` holds the value a UI element such as a health bar should show. When the entity's
+//! `IntegerAttribute` changes, the displayed value eases from where it was to the new value over a fixed duration
+//! instead of snapping, using one of the `Easing` curves. The real attribute is never touched.
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// How a displayed value moves from its old value to its new one.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts fast and slows down as it arrives.
+    #[default]
+    EaseOut,
+    /// Starts slow, speeds up, and slows down again as it arrives.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map the fraction of the duration elapsed, `t`, to the fraction of the distance covered. `t` is clamped to
+    /// 0.0 to 1.0.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0f32).mul_add(t, 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A smoothed copy of an attribute's value for display.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     displayed_attribute::{DisplayedAttribute, Easing},
+///     IntegerAttribute,
+/// };
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut bar = DisplayedAttribute::new(&health, 0.5, Easing::Linear);
+///
+/// health -= 40;
+/// bar.update(&health, 0.25);
+/// assert_eq!(bar.displayed(), 80.0);
+///
+/// bar.update(&health, 0.25);
+/// assert_eq!(bar.displayed(), 60.0);
+/// assert!(!bar.is_animating());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DisplayedAttribute {
+    /// How long, in seconds, the displayed value takes to reach a new value.
+    pub duration: f32,
+    /// The curve the displayed value follows.
+    pub easing: Easing,
+    /// The value shown when the current animation started.
+    from: f32,
+    /// The value being animated towards.
+    target: i32,
+    /// Seconds since the current animation started.
+    elapsed: f32,
+    /// The value to show.
+    displayed: f32,
+}
+
+impl DisplayedAttribute {
+    /// Create a displayed value showing the attribute's current value, animating over `duration` seconds.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub const fn new(attribute: &IntegerAttribute, duration: f32, easing: Easing) -> Self {
+        let current = attribute.current_value();
+        let displayed = current as f32;
+        Self {
+            duration,
+            easing,
+            from: displayed,
+            target: current,
+            elapsed: duration,
+            displayed,
+        }
+    }
+
+    /// The value to show.
+    #[must_use]
+    pub const fn displayed(&self) -> f32 {
+        self.displayed
+    }
+
+    /// The value being animated towards.
+    #[must_use]
+    pub const fn target(&self) -> i32 {
+        self.target
+    }
+
+    /// Check if the displayed value is still moving towards the target.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.elapsed < self.duration
+    }
+
+    /// Advance the animation by `delta_seconds` towards the attribute's current value.
+    ///
+    /// If the attribute changed since the last update, a new animation starts from the value currently shown.
+    /// Returns the value to show.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn update(&mut self, attribute: &IntegerAttribute, delta_seconds: f32) -> f32 {
+        let current = attribute.current_value();
+        if current != self.target {
+            self.from = self.displayed;
+            self.target = current;
+            self.elapsed = 0.0;
+        }
+
+        self.elapsed = (self.elapsed + delta_seconds.max(0.0)).min(self.duration);
+        let target = self.target as f32;
+        self.displayed = if self.duration > 0.0 {
+            let progress = self.easing.apply(self.elapsed / self.duration);
+            (target - self.from).mul_add(progress, self.from)
+        } else {
+            target
+        };
+        self.displayed
+    }
+
+    /// Jump straight to the attribute's current value, ending any animation.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn snap(&mut self, attribute: &IntegerAttribute) {
+        self.target = attribute.current_value();
+        self.displayed = self.target as f32;
+        self.from = self.displayed;
+        self.elapsed = self.duration;
+    }
+}
+
+/// Advance every `DisplayedAttribute` towards the attribute `T` on the same entity by the piped-in delta time (in
+/// seconds).
+pub fn animate_displayed_attributes<T: AttributeComponent>(
+    In(delta_seconds): In<f32>,
+    mut query: Query<(&T, &mut DisplayedAttribute)>,
+) {
+    for (attribute, mut displayed) in &mut query {
+        let attribute = attribute.attribute();
+        if displayed.is_animating() || displayed.target() != attribute.current_value() {
+            displayed.update(attribute, delta_seconds);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! # Downed State
+//!
+//! This module contains the implementation of the `DownedState` component, its transitions, and the systems that
+//! drive it.
+//!
+//! An entity with a `DownedState` moves from alive to downed when its health attribute reaches its minimum value.
+//! While downed, a bleed-out timer counts down. If another player revives the entity before the timer runs out, it
+//! returns to alive with a configured amount of health; otherwise it dies.
+//!
+//! The systems emit a `DownedEvent` for every transition, so games can react without polling the state.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventReader, EventWriter},
+    system::{Commands, In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, Depleted, IntegerAttribute};
+
+/// The life state tracked by a `DownedState`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LifeState {
+    /// The entity is alive and acting normally.
+    #[default]
+    Alive,
+    /// The entity is downed and will die when the bleed-out timer runs out.
+    Downed {
+        /// Seconds remaining before the entity bleeds out.
+        remaining: f32,
+    },
+    /// The entity has bled out. This is terminal until `DownedState::reset` is called.
+    Dead,
+}
+
+/// A transition between life states.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DownedTransition {
+    /// The entity went from alive to downed.
+    Downed,
+    /// The entity was revived while downed.
+    Revived,
+    /// The entity bled out while downed.
+    Died,
+}
+
+/// An event sent whenever an entity's `DownedState` transitions.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DownedEvent {
+    /// The entity that transitioned.
+    pub entity: Entity,
+    /// The transition that happened.
+    pub transition: DownedTransition,
+}
+
+/// An event requesting that a downed entity be revived.
+///
+/// Requests for entities that are not downed are ignored.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReviveRequest {
+    /// The entity to revive.
+    pub entity: Entity,
+}
+
+/// Tracks whether an entity is alive, downed, or dead, based on its health attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{DownedState, DownedTransition, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut state = DownedState::new(10.0, 25);
+///
+/// health -= 100;
+/// assert_eq!(state.update(&health, 0.0), Some(DownedTransition::Downed));
+///
+/// // A teammate gets there in time.
+/// assert_eq!(state.revive(&mut health), Some(DownedTransition::Revived));
+/// assert_eq!(health, 25);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DownedState {
+    /// The current life state.
+    pub state: LifeState,
+    /// How long, in seconds, a downed entity has before it dies.
+    pub bleed_out_duration: f32,
+    /// The health value a revived entity is restored to. It will be clamped to the attribute's min and max.
+    pub revive_health: i32,
+}
+
+impl DownedState {
+    /// Create a new alive state with the given bleed-out duration (in seconds) and revive health.
+    #[must_use]
+    pub const fn new(bleed_out_duration: f32, revive_health: i32) -> Self {
+        Self {
+            state: LifeState::Alive,
+            bleed_out_duration,
+            revive_health,
+        }
+    }
+
+    /// Check if the entity is alive.
+    #[must_use]
+    pub const fn is_alive(&self) -> bool {
+        matches!(self.state, LifeState::Alive)
+    }
+
+    /// Check if the entity is downed.
+    #[must_use]
+    pub const fn is_downed(&self) -> bool {
+        matches!(self.state, LifeState::Downed { .. })
+    }
+
+    /// Check if the entity is dead.
+    #[must_use]
+    pub const fn is_dead(&self) -> bool {
+        matches!(self.state, LifeState::Dead)
+    }
+
+    /// Advance the state machine by `delta_seconds`, given the entity's current health.
+    ///
+    /// An alive entity whose health is at its minimum becomes downed. A downed entity's bleed-out timer counts down,
+    /// and it dies when the timer runs out. Returns the transition that happened, if any.
+    pub fn update(
+        &mut self,
+        health: &IntegerAttribute,
+        delta_seconds: f32,
+    ) -> Option<DownedTransition> {
+        match self.state {
+            LifeState::Alive if health.current_value() <= health.min => {
+                self.state = LifeState::Downed {
+                    remaining: self.bleed_out_duration,
+                };
+                Some(DownedTransition::Downed)
+            }
+            LifeState::Downed { remaining } => {
+                let remaining = remaining - delta_seconds;
+                if remaining <= 0.0 {
+                    self.state = LifeState::Dead;
+                    Some(DownedTransition::Died)
+                } else {
+                    self.state = LifeState::Downed { remaining };
+                    None
+                }
+            }
+            LifeState::Alive | LifeState::Dead => None,
+        }
+    }
+
+    /// Revive a downed entity, restoring its health to `revive_health`.
+    ///
+    /// Returns `None` (and leaves health untouched) if the entity is not downed.
+    pub const fn revive(&mut self, health: &mut IntegerAttribute) -> Option<DownedTransition> {
+        if !self.is_downed() {
+            return None;
+        }
+
+        health.set_value(self.revive_health);
+        self.state = LifeState::Alive;
+        Some(DownedTransition::Revived)
+    }
+
+    /// Reset the state to alive, regardless of the current state. Health is not changed.
+    pub const fn reset(&mut self) {
+        self.state = LifeState::Alive;
+    }
+}
+
+/// Advance every `DownedState` by the piped-in delta time (in seconds), sending a `DownedEvent` for each transition.
+///
+/// The attribute `T` on the same entity is the health the state follows.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use nwest_shared_component_library::{
+///     downed::{update_downed_state, DownedEvent},
+///     vitals::Health,
+/// };
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((|| 0.016_f32).pipe(update_downed_state::<Health>));
+///
+/// let mut world = World::new();
+/// world.init_resource::<Events<DownedEvent>>();
+/// schedule.run(&mut world);
+/// ```
+pub fn update_downed_state<T: AttributeComponent>(
+    In(delta_seconds): In<f32>,
+    mut query: Query<(Entity, &T, &mut DownedState)>,
+    mut events: EventWriter<DownedEvent>,
+) {
+    for (entity, health, mut state) in &mut query {
+        if let Some(transition) = state.update(health.attribute(), delta_seconds) {
+            events.send(DownedEvent { entity, transition });
+        }
+    }
+}
+
+/// Process `ReviveRequest` events for entities whose health is the attribute `T`, sending a `DownedEvent` for each
+/// entity actually revived.
+///
+/// Reviving also removes the entity's `Depleted` marker, so a `DepletionPolicy::Locked` attribute isn't held back at its
+/// minimum.
+pub fn process_revive_requests<T: AttributeComponent>(
+    mut commands: Commands,
+    mut requests: EventReader<ReviveRequest>,
+    mut query: Query<(&mut T, &mut DownedState)>,
+    mut events: EventWriter<DownedEvent>,
+) {
+    for request in requests.read() {
+        let Ok((mut health, mut state)) = query.get_mut(request.entity) else {
+            continue;
+        };
+        if let Some(transition) = state.revive(health.attribute_mut()) {
+            commands.entity(request.entity).remove::<Depleted>();
+            events.send(DownedEvent {
+                entity: request.entity,
+                transition,
+            });
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! # Editor Hints
+//!
+//! This module contains the `EditorHints` trait and the `EditorHint` it returns, which tell inspectors and external
+//! editors how to present a value.
+//!
+//! Without hints, tooling shows raw fields: a `Percent` becomes a float between 0.0 and 1.0, and a
+//! `FixedDecimalAttribute` becomes an integer in thousandths. An `EditorHint` gives a slider range and step in the units
+//! a designer expects, along with how to convert to and from the stored value. Hints are serializable, so they can be
+//! exported alongside the JSON Schema for editors outside the game.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{FixedDecimalAttribute, FloatAttribute, IntegerAttribute, Percent};
+
+/// How an editor should present a value.
+///
+/// `min`, `max`, and `step` are in display units. Multiply a stored value by `display_scale` to get the displayed
+/// value, and divide to go back.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EditorHint {
+    /// The lowest value a slider should offer.
+    pub min: f64,
+    /// The highest value a slider should offer.
+    pub max: f64,
+    /// The smallest change a slider should make.
+    pub step: f64,
+    /// What a stored value is multiplied by to get the displayed value.
+    pub display_scale: f64,
+    /// The unit shown after the value, if any.
+    pub unit: Option<String>,
+}
+
+impl EditorHint {
+    /// Create a hint for a slider from `min` to `max` in steps of `step`, with no scaling or unit.
+    #[must_use]
+    pub const fn new(min: f64, max: f64, step: f64) -> Self {
+        Self {
+            min,
+            max,
+            step,
+            display_scale: 1.0,
+            unit: None,
+        }
+    }
+
+    /// Set what a stored value is multiplied by to get the displayed value.
+    #[must_use]
+    pub const fn with_display_scale(mut self, display_scale: f64) -> Self {
+        self.display_scale = display_scale;
+        self
+    }
+
+    /// Set the unit shown after the value.
+    #[must_use]
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Convert a stored value to the value to display.
+    #[must_use]
+    pub fn to_display(&self, stored: f64) -> f64 {
+        stored * self.display_scale
+    }
+
+    /// Convert a displayed value back to the value to store.
+    #[must_use]
+    pub fn from_display(&self, displayed: f64) -> f64 {
+        displayed / self.display_scale
+    }
+}
+
+/// A value that knows how an editor should present it.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{editor_hints::EditorHints, Percent};
+///
+/// let crit_chance = Percent::new(0.25).expect("Invalid percent");
+/// let hint = crit_chance.editor_hint();
+///
+/// // Shown as a 0-100% slider rather than a raw fraction.
+/// assert_eq!(hint.max, 100.0);
+/// assert_eq!(hint.to_display(crit_chance.fraction()), 25.0);
+/// assert_eq!(hint.unit.as_deref(), Some("%"));
+/// ```
+pub trait EditorHints {
+    /// How an editor should present this value.
+    fn editor_hint(&self) -> EditorHint;
+}
+
+impl EditorHints for IntegerAttribute {
+    /// A slider across the attribute's range in whole steps.
+    fn editor_hint(&self) -> EditorHint {
+        EditorHint::new(f64::from(self.min), f64::from(self.max), 1.0)
+    }
+}
+
+impl<const DECIMALS: u32> EditorHints for FixedDecimalAttribute<DECIMALS> {
+    /// A slider across the attribute's range in whole units, stepping by the smallest unit.
+    #[allow(clippy::cast_precision_loss)]
+    fn editor_hint(&self) -> EditorHint {
+        let scale = Self::SCALE as f64;
+        EditorHint::new(
+            self.min as f64 / scale,
+            self.max as f64 / scale,
+            1.0 / scale,
+        )
+        .with_display_scale(1.0 / scale)
+    }
+}
+
+impl EditorHints for FloatAttribute {
+    /// A slider across the attribute's range in a hundred steps, or steps of 1.0 if the range is empty.
+    fn editor_hint(&self) -> EditorHint {
+        let (min, max) = (f64::from(self.min), f64::from(self.max));
+        let step = if max > min { (max - min) / 100.0 } else { 1.0 };
+        EditorHint::new(min, max, step)
+    }
+}
+
+impl EditorHints for Percent {
+    /// A 0-100% slider in steps of 0.1%.
+    fn editor_hint(&self) -> EditorHint {
+        EditorHint::new(0.0, 100.0, 0.1)
+            .with_display_scale(100.0)
+            .with_unit("%")
+    }
+}
@@ -16,7 +16,38 @@ pub enum AttributeError {
     /// Try to create an attribute with a maximum value less than the minimum value.
     #[error("Maximum value less than minimum value. {0} < {1}")]
     MaxLessThanMin(i32, i32),
+    /// Try to create a `FixedDecimalAttribute` with a minimum value greater than the maximum value. Holds both, in
+    /// units.
+    #[error("Minimum value greater than maximum value. {0} > {1} units")]
+    MinGreaterThanMaxUnits(i64, i64),
+    /// Try to set a `FixedDecimalAttribute` maximum value less than the minimum value. Holds both, in units.
+    #[error("Maximum value less than minimum value. {0} < {1} units")]
+    MaxLessThanMinUnits(i64, i64),
+    /// Try to create a `FloatAttribute` with a minimum value greater than the maximum value. Holds both, formatted,
+    /// since floats would stop errors from being `Eq`, `Hash`, and `Ord`.
+    #[error("Minimum value greater than maximum value. {0} > {1}")]
+    MinGreaterThanMaxFloat(String, String),
+    /// Try to set a `FloatAttribute` maximum value less than the minimum value. Holds both, formatted.
+    #[error("Maximum value less than minimum value. {0} < {1}")]
+    MaxLessThanMinFloat(String, String),
     /// An error when converting an attribute to a type.
     #[error("Conversion error. {0}")]
     ConversionError(String),
+    /// Try to use a currency that a wallet does not hold.
+    #[error("Unknown currency. {0}")]
+    UnknownCurrency(String),
+    /// Try to spend more of a currency than is available. Holds the currency, the amount, and what was available.
+    #[error("Insufficient funds. {1} {0} needed but only {2} available")]
+    InsufficientFunds(String, i64, i64),
+    /// Try to add more of a currency than there is room for. Holds the currency, the amount, and the room left.
+    #[error("Insufficient capacity. {1} {0} needed but only room for {2}")]
+    InsufficientCapacity(String, i64, i64),
+    /// A change would take an attribute outside of its bounds. Holds the value it would have had, the minimum, and the
+    /// maximum.
+    #[error("Out of bounds. {0} is not between {1} and {2}")]
+    OutOfBounds(i64, i64, i64),
+    /// A change in a `StatTransaction` failed, so none of its changes were applied. Holds the label of the change and
+    /// why it failed.
+    #[error("Transaction failed at '{0}'. {1}")]
+    TransactionFailed(String, Box<Self>),
 }
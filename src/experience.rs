@@ -0,0 +1,216 @@
+//! # Experience
+//!
+//! This module contains the implementation of the `Level` component and the `LevelCurve` trait.
+//!
+//! A `Level` tracks a level and the experience earned towards the next one. How much experience each level needs is
+//! decided by a `LevelCurve`, so the same component works for linear, exponential, or hand-tuned progression. Three
+//! curves are provided: `LinearCurve`, `ExponentialCurve`, and `TableCurve`.
+
+use bevy_ecs::{component::Component, system::Resource};
+use serde::{Deserialize, Serialize};
+
+/// Decides how much experience is needed to advance from one level to the next.
+pub trait LevelCurve {
+    /// The experience required to advance from `level` to `level + 1`.
+    ///
+    /// Returns `None` if `level` is the highest level the curve allows.
+    fn experience_to_next(&self, level: u32) -> Option<u64>;
+
+    /// Spend `experience` starting at `level`, returning the level reached and the experience left over.
+    ///
+    /// The default advances one level at a time. Curves that can work out many levels at once should override it, so
+    /// that a huge grant doesn't take a step per level.
+    fn advance(&self, level: u32, experience: u64) -> (u32, u64) {
+        advance_one_at_a_time(self, level, experience)
+    }
+}
+
+/// Spend experience one level at a time, stopping at the curve's maximum level, at a level that requires no
+/// experience, or when the next level can't be afforded.
+fn advance_one_at_a_time<C: LevelCurve + ?Sized>(
+    curve: &C,
+    mut level: u32,
+    mut experience: u64,
+) -> (u32, u64) {
+    while let Some(required) = curve.experience_to_next(level) {
+        // A curve that asks for no experience would otherwise level forever.
+        if required == 0 || experience < required || level == u32::MAX {
+            break;
+        }
+        experience -= required;
+        level += 1;
+    }
+    (level, experience)
+}
+
+/// A curve where each level needs a fixed amount more experience than the last.
+///
+/// Advancing from level `n` requires `base + increment * (n - 1)` experience.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Resource)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinearCurve {
+    /// The experience required to advance from level 1.
+    pub base: u64,
+    /// The additional experience required for each level after the first.
+    pub increment: u64,
+}
+
+impl LevelCurve for LinearCurve {
+    fn experience_to_next(&self, level: u32) -> Option<u64> {
+        Some(
+            self.base.saturating_add(
+                self.increment
+                    .saturating_mul(u64::from(level.saturating_sub(1))),
+            ),
+        )
+    }
+
+    /// Work out the number of levels gained in closed form, then finish one level at a time.
+    fn advance(&self, level: u32, experience: u64) -> (u32, u64) {
+        let first = u128::from(self.base)
+            + u128::from(self.increment) * u128::from(level.saturating_sub(1));
+        if first == 0 {
+            return (level, experience);
+        }
+
+        // The experience needed for the next `levels` levels. Saturating keeps huge sums above any `u64`.
+        let increment = u128::from(self.increment);
+        let cost = |levels: u128| {
+            levels.saturating_mul(first).saturating_add(
+                increment.saturating_mul(levels.saturating_mul(levels.saturating_sub(1)) / 2),
+            )
+        };
+
+        // Binary search for the most levels that can be afforded.
+        let budget = u128::from(experience);
+        let (mut low, mut high) = (0, u128::from(u32::MAX - level));
+        while low < high {
+            let middle = low + (high - low).div_ceil(2);
+            if cost(middle) <= budget {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        // Both fit, since `low` is at most `u32::MAX - level` levels costing at most `experience`.
+        let gained = u32::try_from(low).unwrap_or(0);
+        let spent = u64::try_from(cost(low)).unwrap_or(0);
+        advance_one_at_a_time(self, level + gained, experience - spent)
+    }
+}
+
+/// A curve where each level needs a multiple of the experience of the last.
+///
+/// Advancing from level `n` requires `base * factor^(n - 1)` experience, rounded to the nearest point.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Resource)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExponentialCurve {
+    /// The experience required to advance from level 1.
+    pub base: u64,
+    /// The growth factor applied for each level after the first.
+    pub factor: f64,
+}
+
+impl LevelCurve for ExponentialCurve {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss,
+        clippy::cast_possible_wrap
+    )]
+    fn experience_to_next(&self, level: u32) -> Option<u64> {
+        let exponent = level.saturating_sub(1).min(i32::MAX as u32) as i32;
+        // Float to integer casts saturate, so very large levels cap at `u64::MAX`.
+        Some((self.base as f64 * self.factor.powi(exponent)).round() as u64)
+    }
+}
+
+/// A hand-tuned curve where the experience for each level is listed explicitly.
+///
+/// The first entry is the experience required to advance from level 1, the second from level 2, and so on. The
+/// level after the last entry is the maximum level.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash, Resource)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TableCurve {
+    /// The experience required to advance from each level, starting at level 1.
+    pub thresholds: Vec<u64>,
+}
+
+impl LevelCurve for TableCurve {
+    fn experience_to_next(&self, level: u32) -> Option<u64> {
+        let index = usize::try_from(level.checked_sub(1)?).ok()?;
+        self.thresholds.get(index).copied()
+    }
+}
+
+/// A level and the experience earned towards the next one.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::experience::{Level, LinearCurve};
+///
+/// let curve = LinearCurve { base: 100, increment: 50 };
+/// let mut level = Level::default();
+///
+/// // 100 experience for level 2, then 150 for level 3.
+/// assert_eq!(level.add_experience(260, &curve), 2);
+/// assert_eq!(level.level, 3);
+/// assert_eq!(level.experience, 10);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Level {
+    /// The current level. Levels start at 1.
+    pub level: u32,
+    /// The experience earned towards the next level.
+    pub experience: u64,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Level {
+    /// Create a new level with no experience towards the next. A level of 0 is treated as 1.
+    #[must_use]
+    pub const fn new(level: u32) -> Self {
+        Self {
+            level: if level == 0 { 1 } else { level },
+            experience: 0,
+        }
+    }
+
+    /// Add experience, advancing as many levels as it pays for.
+    ///
+    /// Returns the number of levels gained. Once the curve's maximum level is reached, further experience is still
+    /// recorded but no more levels are gained. Levels that require no experience are treated as a maximum level.
+    pub fn add_experience(&mut self, amount: u64, curve: &impl LevelCurve) -> u32 {
+        self.experience = self.experience.saturating_add(amount);
+
+        let before = self.level;
+        (self.level, self.experience) = curve.advance(self.level, self.experience);
+        self.level - before
+    }
+
+    /// The experience still needed to reach the next level, or `None` at the curve's maximum level.
+    #[must_use]
+    pub fn experience_remaining(&self, curve: &impl LevelCurve) -> Option<u64> {
+        curve
+            .experience_to_next(self.level)
+            .map(|required| required.saturating_sub(self.experience))
+    }
+
+    /// The fraction of the way to the next level, between 0 and 1. At the maximum level this is 1.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn progress(&self, curve: &impl LevelCurve) -> f32 {
+        match curve.experience_to_next(self.level) {
+            Some(0) | None => 1.0,
+            Some(required) => (self.experience as f32 / required as f32).min(1.0),
+        }
+    }
+}
@@ -0,0 +1,517 @@
+//! # Fixed Decimal Attribute
+//!
+//! This module contains the implementation of the `FixedDecimalAttribute` struct and its methods and implementations.
+//!
+//! The `FixedDecimalAttribute` struct holds a decimal value as a whole number of fixed-size units (thousandths by
+//! default). Because the value is stored as an integer, totals never pick up floating point rounding error, which
+//! makes it suitable for gold, currency, and other values that players add up and compare exactly.
+
+use bevy_ecs::{component::Component, system::Resource};
+use serde::{Deserialize, Serialize};
+
+use crate::AttributeError;
+
+/// A decimal attribute stored as integer units, with `DECIMALS` digits after the decimal point.
+///
+/// All values (`min`, `max`, `current`, and the operands of the arithmetic operators) are expressed in units. With
+/// the default of 3 decimals, a value of `1_500` units is `1.500`. Use `parse_units` to convert decimal strings to
+/// units, and the `Display` implementation to format units as a decimal string.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::FixedDecimalAttribute;
+///
+/// // A purse that can hold up to 1000.00 gold, using cents.
+/// let mut gold = FixedDecimalAttribute::<2>::new(100_000);
+/// gold.set_value(0);
+///
+/// gold += FixedDecimalAttribute::<2>::parse_units("0.10").expect("Failed to parse");
+/// gold += FixedDecimalAttribute::<2>::parse_units("0.20").expect("Failed to parse");
+///
+/// // No floating point error: 0.10 + 0.20 is exactly 0.30.
+/// assert_eq!(gold, 30);
+/// assert_eq!(gold.to_string(), "0.30");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Component, Resource, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixedDecimalAttribute<const DECIMALS: u32 = 3> {
+    /// The maximum value of the attribute, in units.
+    ///
+    /// # Note
+    ///
+    /// Setting this value directly can result in the maximum becoming less than the minimum. If you need to set the maximum value, use the `set_max` method.
+    pub max: i64,
+    /// The minimum value of the attribute, in units.
+    ///
+    /// # Note
+    ///
+    /// Setting this value directly can result in the minimum becoming greater than the maximum. If you need to set the minimum value, use the `set_min` method.
+    pub min: i64,
+    /// The current value of the attribute, in units.
+    ///
+    /// Clamped between `min` and `max`.
+    pub current: i64,
+}
+
+impl<const DECIMALS: u32> FixedDecimalAttribute<DECIMALS> {
+    /// The number of units in one whole. `DECIMALS` must be at most 18 so this fits in an `i64`.
+    pub const SCALE: i64 = 10_i64.pow(DECIMALS);
+
+    /// Create a new attribute with the given maximum, in units.
+    ///
+    /// The minimum value will be set to 0, and the current value will be set to the maximum value.
+    ///
+    /// If a negative maximum is provided, minimum will be clamped to the maximum value.
+    #[must_use]
+    pub const fn new(max: i64) -> Self {
+        Self {
+            min: if max < 0 { max } else { 0 },
+            max,
+            current: max,
+        }
+    }
+
+    /// Start building an attribute. See `FixedDecimalAttributeBuilder`.
+    pub fn builder() -> FixedDecimalAttributeBuilder<DECIMALS> {
+        FixedDecimalAttributeBuilder::default()
+    }
+
+    /// Create a new attribute with the given values, in units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value.
+    pub fn new_as_defined(min: i64, max: i64, current: i64) -> Result<Self, AttributeError> {
+        if min > max {
+            return Err(AttributeError::MinGreaterThanMaxUnits(min, max));
+        }
+
+        Ok(Self {
+            min,
+            max,
+            current: current.clamp(min, max),
+        })
+    }
+
+    /// Create a new attribute with a defined maximum and minimum value, in units.
+    ///
+    /// The current value will be set to the maximum value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value.
+    pub fn with_min_and_max(min: i64, max: i64) -> Result<Self, AttributeError> {
+        Self::new_as_defined(min, max, max)
+    }
+
+    /// Convert a decimal string such as `"12.5"` or `"-0.125"` to units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a decimal number, has more than `DECIMALS` digits after the decimal
+    /// point, or does not fit in an `i64` number of units.
+    pub fn parse_units(value: &str) -> Result<i64, AttributeError> {
+        let error = || {
+            AttributeError::ConversionError(format!(
+                "'{value}' is not a decimal with at most {DECIMALS} decimal places."
+            ))
+        };
+
+        let trimmed = value.trim();
+        let (negative, digits) = trimmed
+            .strip_prefix('-')
+            .map_or((false, trimmed), |digits| (true, digits));
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty())
+            || !all_digits(whole)
+            || !all_digits(fraction)
+            || fraction.len() > DECIMALS as usize
+        {
+            return Err(error());
+        }
+
+        let whole: i64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| error())?
+        };
+        let fraction: i64 = if fraction.is_empty() {
+            0
+        } else {
+            let padding = DECIMALS - u32::try_from(fraction.len()).map_err(|_| error())?;
+            fraction.parse::<i64>().map_err(|_| error())? * 10_i64.pow(padding)
+        };
+
+        let units = whole
+            .checked_mul(Self::SCALE)
+            .and_then(|units| units.checked_add(fraction))
+            .ok_or_else(error)?;
+
+        Ok(if negative { -units } else { units })
+    }
+
+    /// Format a number of units as a decimal string with exactly `DECIMALS` decimal places.
+    #[must_use]
+    pub fn format_units(units: i64) -> String {
+        let sign = if units < 0 { "-" } else { "" };
+        let magnitude = units.unsigned_abs();
+        let scale = Self::SCALE.unsigned_abs();
+        let whole = magnitude / scale;
+
+        if DECIMALS == 0 {
+            format!("{sign}{whole}")
+        } else {
+            let fraction = magnitude % scale;
+            format!(
+                "{sign}{whole}.{fraction:0width$}",
+                width = DECIMALS as usize
+            )
+        }
+    }
+
+    /// Set the current value of the attribute, in units. It will be clamped between `min` and `max`.
+    pub const fn set_value(&mut self, current: i64) {
+        self.current = clamp(current, self.min, self.max);
+    }
+
+    /// Get the current value of the attribute, in units.
+    ///
+    /// This will be clamped between `min` and `max`.
+    #[must_use]
+    pub const fn current_value(&self) -> i64 {
+        clamp(self.current, self.min, self.max)
+    }
+
+    /// Get the whole part of the current value, truncated towards zero.
+    #[must_use]
+    pub const fn whole(&self) -> i64 {
+        self.current_value() / Self::SCALE
+    }
+
+    /// Get the current value as an `f64`.
+    ///
+    /// This is meant for display and interop only; do arithmetic on the units to keep it exact.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        self.current_value() as f64 / Self::SCALE as f64
+    }
+
+    /// Get the percentage of the current value between the minimum and maximum values.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn current_percentage(&self) -> f32 {
+        let range = i128::from(self.max) - i128::from(self.min);
+        if range == 0 {
+            return 1.0;
+        }
+        ((i128::from(self.current_value()) - i128::from(self.min)) as f64 / range as f64) as f32
+    }
+
+    /// Multiply the current value by `numerator / denominator`, rounding half away from zero to the nearest unit.
+    ///
+    /// This is the exact way to apply a percentage, such as a 15% tax (`mul_ratio(15, 100)`). The result is clamped
+    /// between `min` and `max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `denominator` is zero.
+    pub fn mul_ratio(&mut self, numerator: i64, denominator: i64) -> Result<(), AttributeError> {
+        if denominator == 0 {
+            return Err(AttributeError::ConversionError(
+                "Cannot multiply by a ratio with a zero denominator.".to_string(),
+            ));
+        }
+
+        let product = i128::from(self.current) * i128::from(numerator);
+        let denominator = i128::from(denominator);
+        let half = denominator.abs() / 2;
+        let rounded = if (product < 0) == (denominator < 0) {
+            (product.abs() + half) / denominator.abs()
+        } else {
+            -((product.abs() + half) / denominator.abs())
+        };
+
+        self.current = i64::try_from(rounded.clamp(i128::from(self.min), i128::from(self.max)))
+            .unwrap_or(self.current);
+        Ok(())
+    }
+
+    /// Set the max value of the attribute, in units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maximum value is less than the minimum value.
+    pub fn set_max(&mut self, value: i64) -> Result<(), AttributeError> {
+        if value < self.min {
+            return Err(AttributeError::MaxLessThanMinUnits(value, self.min));
+        }
+
+        self.max = value;
+        self.current = self.current.clamp(self.min, self.max);
+
+        Ok(())
+    }
+
+    /// Set the min value of the attribute, in units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value.
+    pub fn set_min(&mut self, value: i64) -> Result<(), AttributeError> {
+        if value > self.max {
+            return Err(AttributeError::MinGreaterThanMaxUnits(value, self.max));
+        }
+
+        self.min = value;
+        self.current = self.current.clamp(self.min, self.max);
+
+        Ok(())
+    }
+
+    /// Set the current value (in units), failing without any change if it is outside of `min` and `max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the value is outside of `min` and `max`.
+    pub fn try_set_value(&mut self, current: i64) -> Result<(), AttributeError> {
+        self.current = self.check_bounds(i128::from(current))?;
+        Ok(())
+    }
+
+    /// Add units to the current value, failing without any change if the result would be outside of `min` and `max`.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the result would be outside of `min` and `max`.
+    pub fn try_add(&mut self, rhs: i64) -> Result<i64, AttributeError> {
+        self.current = self.check_bounds(i128::from(self.current) + i128::from(rhs))?;
+        Ok(self.current)
+    }
+
+    /// Subtract units from the current value, failing without any change if the result would be outside of `min` and
+    /// `max`.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the result would be outside of `min` and `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nwest_shared_component_library::FixedDecimalAttribute;
+    ///
+    /// let mut stamina = FixedDecimalAttribute::<3>::new(5_000);
+    /// assert_eq!(stamina.try_subtract(4_500), Ok(500));
+    /// assert!(stamina.try_subtract(501).is_err());
+    /// assert_eq!(stamina, 500);
+    /// ```
+    pub fn try_subtract(&mut self, rhs: i64) -> Result<i64, AttributeError> {
+        self.current = self.check_bounds(i128::from(self.current) - i128::from(rhs))?;
+        Ok(self.current)
+    }
+
+    /// Check that a value is between `min` and `max`.
+    fn check_bounds(&self, value: i128) -> Result<i64, AttributeError> {
+        i64::try_from(value)
+            .ok()
+            .filter(|value| (self.min..=self.max).contains(value))
+            .ok_or_else(|| {
+                // Report values too large for an `i64` at the nearest `i64` limit.
+                let reported =
+                    i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX });
+                AttributeError::OutOfBounds(reported, self.min, self.max)
+            })
+    }
+}
+
+/// A builder for `FixedDecimalAttribute`, for setting only the values that matter.
+///
+/// The minimum defaults to 0, or to the maximum if the maximum is negative, like `FixedDecimalAttribute::new`. The maximum defaults
+/// to 0 and the current value defaults to the maximum.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::FixedDecimalAttribute;
+///
+/// let gold = FixedDecimalAttribute::<2>::builder().max(100_000).current(2_550).build().expect("Invalid attribute");
+/// assert_eq!(gold.to_string(), "25.50");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[must_use]
+pub struct FixedDecimalAttributeBuilder<const DECIMALS: u32 = 3> {
+    /// The minimum value, if set.
+    min: Option<i64>,
+    /// The maximum value.
+    max: i64,
+    /// The current value, if set.
+    current: Option<i64>,
+}
+
+impl<const DECIMALS: u32> FixedDecimalAttributeBuilder<DECIMALS> {
+    /// Set the minimum value.
+    pub const fn min(mut self, min: i64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value.
+    pub const fn max(mut self, max: i64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the current value. It will be clamped between the minimum and maximum.
+    pub const fn current(mut self, current: i64) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// Build the attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value.
+    pub fn build(self) -> Result<FixedDecimalAttribute<DECIMALS>, AttributeError> {
+        let min = self.min.unwrap_or(if self.max < 0 { self.max } else { 0 });
+        let current = self.current.unwrap_or(self.max);
+        FixedDecimalAttribute::<DECIMALS>::new_as_defined(min, self.max, current)
+    }
+}
+
+/// `Ord::clamp` for `i64`, usable in `const` functions. Panics if `min > max`, like `Ord::clamp`.
+const fn clamp(value: i64, min: i64, max: i64) -> i64 {
+    assert!(min <= max, "min > max");
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+impl<const DECIMALS: u32> PartialEq for FixedDecimalAttribute<DECIMALS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.current == other.current
+    }
+}
+
+impl<const DECIMALS: u32> PartialEq<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn eq(&self, other: &i64) -> bool {
+        self.current == *other
+    }
+}
+
+impl<const DECIMALS: u32> PartialEq<FixedDecimalAttribute<DECIMALS>> for i64 {
+    fn eq(&self, other: &FixedDecimalAttribute<DECIMALS>) -> bool {
+        *self == other.current
+    }
+}
+
+impl<const DECIMALS: u32> Eq for FixedDecimalAttribute<DECIMALS> {}
+
+impl<const DECIMALS: u32> std::hash::Hash for FixedDecimalAttribute<DECIMALS> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.current.hash(state);
+    }
+}
+
+impl<const DECIMALS: u32> std::fmt::Display for FixedDecimalAttribute<DECIMALS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&Self::format_units(self.current))
+    }
+}
+
+/// Allow conversion of `FixedDecimalAttribute` to its value in units.
+impl<const DECIMALS: u32> From<FixedDecimalAttribute<DECIMALS>> for i64 {
+    fn from(attribute: FixedDecimalAttribute<DECIMALS>) -> Self {
+        attribute.current
+    }
+}
+
+/// Allow a `FixedDecimalAttribute` to be used wherever an `&i64` is expected, reading the current value in units.
+impl<const DECIMALS: u32> std::ops::Deref for FixedDecimalAttribute<DECIMALS> {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+/// Allow borrowing the current value of a `FixedDecimalAttribute`, in units.
+impl<const DECIMALS: u32> AsRef<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn as_ref(&self) -> &i64 {
+        &self.current
+    }
+}
+
+/// Allow addition of `FixedDecimalAttribute` and a number of units.
+impl<const DECIMALS: u32> std::ops::Add<i64> for FixedDecimalAttribute<DECIMALS> {
+    type Output = Self;
+
+    fn add(mut self, rhs: i64) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Allow addition of a number of units to `FixedDecimalAttribute` with assignment.
+impl<const DECIMALS: u32> std::ops::AddAssign<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn add_assign(&mut self, rhs: i64) {
+        self.current = self.current.saturating_add(rhs).clamp(self.min, self.max);
+    }
+}
+
+/// Allow subtraction of a number of units from `FixedDecimalAttribute`.
+impl<const DECIMALS: u32> std::ops::Sub<i64> for FixedDecimalAttribute<DECIMALS> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: i64) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Allow subtraction of a number of units from `FixedDecimalAttribute` with assignment.
+impl<const DECIMALS: u32> std::ops::SubAssign<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn sub_assign(&mut self, rhs: i64) {
+        self.current = self.current.saturating_sub(rhs).clamp(self.min, self.max);
+    }
+}
+
+/// Allow multiplication of `FixedDecimalAttribute` by a whole number.
+impl<const DECIMALS: u32> std::ops::Mul<i64> for FixedDecimalAttribute<DECIMALS> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: i64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+/// Allow multiplication of `FixedDecimalAttribute` by a whole number with assignment.
+impl<const DECIMALS: u32> std::ops::MulAssign<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn mul_assign(&mut self, rhs: i64) {
+        self.current = self.current.saturating_mul(rhs).clamp(self.min, self.max);
+    }
+}
+
+/// Range of `FixedDecimalAttribute` values, in units.
+impl<const DECIMALS: u32> std::ops::RangeBounds<i64> for FixedDecimalAttribute<DECIMALS> {
+    fn start_bound(&self) -> std::ops::Bound<&i64> {
+        std::ops::Bound::Included(&self.min)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&i64> {
+        std::ops::Bound::Included(&self.max)
+    }
+}
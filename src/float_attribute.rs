@@ -0,0 +1,367 @@
+//! # Float Attribute
+//!
+//! This module contains the implementation of the `FloatAttribute` struct and its methods and implementations.
+//!
+//! The `FloatAttribute` struct holds an `f32` value with a minimum and maximum it is clamped to. At 12 bytes it is half
+//! the size of a `FixedDecimalAttribute`, which matters for projects that replicate thousands of attributes. Use it for
+//! values where small rounding error is acceptable, such as movement speed or attack speed; use
+//! `FixedDecimalAttribute` for values that are added up and compared exactly.
+//!
+//! NaN is never stored: constructors reject NaN bounds, and setting or adding NaN leaves the value unchanged.
+
+use bevy_ecs::{component::Component, system::Resource};
+use serde::{Deserialize, Serialize};
+
+use crate::AttributeError;
+
+/// A floating point attribute that can be used to represent things like movement speed, attack speed, etc.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::FloatAttribute;
+///
+/// // Movement speed between 0.0 and 10.0, starting at 5.0.
+/// let mut speed = FloatAttribute::new_as_defined(0.0, 10.0, 5.0).expect("Failed to create attribute");
+///
+/// speed *= 1.5;
+/// assert_eq!(speed, 7.5);
+///
+/// speed += 10.0;
+/// assert_eq!(speed, 10.0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Component, Resource, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FloatAttribute {
+    /// The maximum value of the attribute.
+    ///
+    /// # Note
+    ///
+    /// Setting this value directly can result in the maximum becoming less than the minimum. If you need to set the maximum value, use the `set_max` method.
+    pub max: f32,
+    /// The minimum value of the attribute.
+    ///
+    /// # Note
+    ///
+    /// Setting this value directly can result in the minimum becoming greater than the maximum. If you need to set the minimum value, use the `set_min` method.
+    pub min: f32,
+    /// The current value of the attribute.
+    ///
+    /// Clamped between `min` and `max`.
+    pub current: f32,
+}
+
+impl FloatAttribute {
+    /// Create a new float attribute with the given maximum.
+    ///
+    /// The minimum value will be set to 0.0, and the current value will be set to the maximum value.
+    ///
+    /// If a negative maximum is provided, minimum will be clamped to the maximum value. A NaN maximum is treated as 0.0.
+    #[must_use]
+    pub const fn new(max: f32) -> Self {
+        let max = if max.is_nan() { 0.0 } else { max };
+        Self {
+            min: max.min(0.0),
+            max,
+            current: max,
+        }
+    }
+
+    /// Start building an attribute. See `FloatAttributeBuilder`.
+    pub fn builder() -> FloatAttributeBuilder {
+        FloatAttributeBuilder::default()
+    }
+
+    /// Create a new float attribute with the given values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value, or if any value is NaN.
+    pub fn new_as_defined(min: f32, max: f32, current: f32) -> Result<Self, AttributeError> {
+        if min.is_nan() || max.is_nan() || current.is_nan() {
+            return Err(AttributeError::AttributeError(format!(
+                "Float attribute values cannot be NaN. {min}, {max}, {current}"
+            )));
+        }
+        if min > max {
+            return Err(AttributeError::MinGreaterThanMaxFloat(
+                min.to_string(),
+                max.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            min,
+            max,
+            current: current.clamp(min, max),
+        })
+    }
+
+    /// Create a new float attribute with a defined maximum and minimum value.
+    ///
+    /// The current value will be set to the maximum value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value, or if either value is NaN.
+    pub fn with_min_and_max(min: f32, max: f32) -> Result<Self, AttributeError> {
+        Self::new_as_defined(min, max, max)
+    }
+
+    /// Set the current value of the attribute. It will be clamped between `min` and `max`. NaN is ignored.
+    pub const fn set_value(&mut self, current: f32) {
+        if !current.is_nan() {
+            self.current = current.max(self.min).min(self.max);
+        }
+    }
+
+    /// Get the current value of the attribute.
+    ///
+    /// This will be clamped between `min` and `max`.
+    #[must_use]
+    pub const fn current_value(&self) -> f32 {
+        self.current.max(self.min).min(self.max)
+    }
+
+    /// Get the percentage of the current value between the minimum and maximum values.
+    #[must_use]
+    pub fn current_percentage(&self) -> f32 {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            return 1.0;
+        }
+        (self.current_value() - self.min) / range
+    }
+
+    /// Set the max value of the attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maximum value is less than the minimum value, or is NaN.
+    pub fn set_max(&mut self, value: f32) -> Result<(), AttributeError> {
+        if value.is_nan() {
+            return Err(AttributeError::AttributeError(
+                "Float attribute values cannot be NaN.".to_string(),
+            ));
+        }
+        if value < self.min {
+            return Err(AttributeError::MaxLessThanMinFloat(
+                value.to_string(),
+                self.min.to_string(),
+            ));
+        }
+
+        self.max = value;
+        self.current = self.current_value();
+
+        Ok(())
+    }
+
+    /// Set the min value of the attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value, or is NaN.
+    pub fn set_min(&mut self, value: f32) -> Result<(), AttributeError> {
+        if value.is_nan() {
+            return Err(AttributeError::AttributeError(
+                "Float attribute values cannot be NaN.".to_string(),
+            ));
+        }
+        if value > self.max {
+            return Err(AttributeError::MinGreaterThanMaxFloat(
+                value.to_string(),
+                self.max.to_string(),
+            ));
+        }
+
+        self.min = value;
+        self.current = self.current_value();
+
+        Ok(())
+    }
+}
+
+/// A builder for `FloatAttribute`, for setting only the values that matter.
+///
+/// The minimum defaults to 0, or to the maximum if the maximum is negative, like `FloatAttribute::new`. The maximum defaults
+/// to 0 and the current value defaults to the maximum.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::FloatAttribute;
+///
+/// let temperature = FloatAttribute::builder().min(30.0).max(45.0).current(37.0).build().expect("Invalid attribute");
+/// assert_eq!(temperature, 37.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[must_use]
+pub struct FloatAttributeBuilder {
+    /// The minimum value, if set.
+    min: Option<f32>,
+    /// The maximum value.
+    max: f32,
+    /// The current value, if set.
+    current: Option<f32>,
+}
+
+impl FloatAttributeBuilder {
+    /// Set the minimum value.
+    pub const fn min(mut self, min: f32) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value.
+    pub const fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the current value. It will be clamped between the minimum and maximum.
+    pub const fn current(mut self, current: f32) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// Build the attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value, or if any value is NaN.
+    pub fn build(self) -> Result<FloatAttribute, AttributeError> {
+        let min = self.min.unwrap_or_else(|| self.max.min(0.0));
+        let current = self.current.unwrap_or(self.max);
+        FloatAttribute::new_as_defined(min, self.max, current)
+    }
+}
+
+impl PartialEq for FloatAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.current == other.current
+    }
+}
+
+impl PartialEq<f32> for FloatAttribute {
+    fn eq(&self, other: &f32) -> bool {
+        self.current == *other
+    }
+}
+
+impl PartialEq<FloatAttribute> for f32 {
+    fn eq(&self, other: &FloatAttribute) -> bool {
+        *self == other.current
+    }
+}
+
+impl std::fmt::Display for FloatAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:.2}%)", self.current, self.current_percentage())
+    }
+}
+
+/// Allow conversion of `FloatAttribute` to f32.
+impl From<FloatAttribute> for f32 {
+    fn from(attribute: FloatAttribute) -> Self {
+        attribute.current
+    }
+}
+
+/// Allow a `FloatAttribute` to be used wherever an `&f32` is expected, reading the current value.
+impl std::ops::Deref for FloatAttribute {
+    type Target = f32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+/// Allow borrowing the current value of a `FloatAttribute`.
+impl AsRef<f32> for FloatAttribute {
+    fn as_ref(&self) -> &f32 {
+        &self.current
+    }
+}
+
+/// Allow addition of `FloatAttribute` and `f32`.
+impl std::ops::Add<f32> for FloatAttribute {
+    type Output = Self;
+
+    fn add(mut self, rhs: f32) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Allow addition of `FloatAttribute` and `f32` with assignment.
+impl std::ops::AddAssign<f32> for FloatAttribute {
+    fn add_assign(&mut self, rhs: f32) {
+        self.set_value(self.current + rhs);
+    }
+}
+
+/// Allow subtraction of `FloatAttribute` and `f32`.
+impl std::ops::Sub<f32> for FloatAttribute {
+    type Output = Self;
+
+    fn sub(mut self, rhs: f32) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+/// Allow subtraction of `FloatAttribute` and `f32` with assignment.
+impl std::ops::SubAssign<f32> for FloatAttribute {
+    fn sub_assign(&mut self, rhs: f32) {
+        self.set_value(self.current - rhs);
+    }
+}
+
+/// Allow multiplication of `FloatAttribute` and `f32`.
+impl std::ops::Mul<f32> for FloatAttribute {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+/// Allow multiplication of `FloatAttribute` and `f32` with assignment.
+impl std::ops::MulAssign<f32> for FloatAttribute {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.set_value(self.current * rhs);
+    }
+}
+
+/// Allow division of `FloatAttribute` and `f32`.
+///
+/// Dividing by zero moves the value to `min` or `max` (following the sign of the infinity), or leaves it unchanged
+/// for `0.0 / 0.0`.
+impl std::ops::Div<f32> for FloatAttribute {
+    type Output = Self;
+
+    fn div(mut self, rhs: f32) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+/// Allow division of `FloatAttribute` and `f32` with assignment.
+impl std::ops::DivAssign<f32> for FloatAttribute {
+    fn div_assign(&mut self, rhs: f32) {
+        self.set_value(self.current / rhs);
+    }
+}
+
+/// Range of `FloatAttribute` values.
+impl std::ops::RangeBounds<f32> for FloatAttribute {
+    fn start_bound(&self) -> std::ops::Bound<&f32> {
+        std::ops::Bound::Included(&self.min)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&f32> {
+        std::ops::Bound::Included(&self.max)
+    }
+}
@@ -0,0 +1,116 @@
+//! # Gauge Attribute
+//!
+//! This module contains the implementation of the `GaugeAttribute` component and the system that advances it.
+//!
+//! A `GaugeAttribute` is a `FloatAttribute` that drifts on its own at a fixed rate, such as hunger or thirst draining
+//! over time or body temperature rising in a desert. Because the value is a float, slow rates apply smoothly every
+//! frame without carrying fractions between ticks.
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::FloatAttribute;
+
+/// A float attribute that changes by `rate_per_second` on its own.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{FloatAttribute, GaugeAttribute};
+///
+/// // Hunger drains from 100 to 0 over 200 seconds.
+/// let mut hunger = GaugeAttribute::new(FloatAttribute::new(100.0), -0.5);
+///
+/// hunger.advance(60.0);
+/// assert_eq!(hunger.attribute, 70.0);
+///
+/// hunger.advance(1_000.0);
+/// assert!(hunger.is_empty());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GaugeAttribute {
+    /// The value of the gauge.
+    pub attribute: FloatAttribute,
+    /// How much the gauge changes per second. Negative values decay it, positive values grow it.
+    pub rate_per_second: f32,
+}
+
+impl GaugeAttribute {
+    /// Create a new gauge that changes by `rate_per_second`.
+    #[must_use]
+    pub const fn new(attribute: FloatAttribute, rate_per_second: f32) -> Self {
+        Self {
+            attribute,
+            rate_per_second,
+        }
+    }
+
+    /// The current value of the gauge.
+    #[must_use]
+    pub const fn current_value(&self) -> f32 {
+        self.attribute.current_value()
+    }
+
+    /// Check if the gauge is at its minimum.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.current_value() <= self.attribute.min
+    }
+
+    /// Check if the gauge is at its maximum.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.current_value() >= self.attribute.max
+    }
+
+    /// Seconds until the gauge reaches the bound it is moving towards, or `None` if it isn't moving towards one.
+    #[must_use]
+    pub fn seconds_until_bound(&self) -> Option<f32> {
+        if self.rate_per_second < 0.0 {
+            Some((self.current_value() - self.attribute.min) / -self.rate_per_second)
+        } else if self.rate_per_second > 0.0 {
+            Some((self.attribute.max - self.current_value()) / self.rate_per_second)
+        } else {
+            None
+        }
+    }
+
+    /// Advance the gauge by `delta_seconds`, clamped to the attribute's min and max.
+    ///
+    /// Returns the amount the gauge actually changed by.
+    pub fn advance(&mut self, delta_seconds: f32) -> f32 {
+        let before = self.current_value();
+        self.attribute += self.rate_per_second * delta_seconds;
+        self.current_value() - before
+    }
+
+    /// Fast-forward the gauge by `seconds`, such as the time a game was closed, in a single step.
+    ///
+    /// This is `advance` computed in `f64`. Negative or non-finite durations are ignored. Returns the amount the gauge
+    /// actually changed by.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn simulate_elapsed(&mut self, seconds: f64) -> f32 {
+        if !seconds.is_finite() || seconds < 0.0 {
+            return 0.0;
+        }
+
+        let before = self.current_value();
+        let target = f64::from(self.rate_per_second).mul_add(seconds, f64::from(before));
+        // Out of range values become infinite, which `set_value` clamps.
+        self.attribute.set_value(target as f32);
+        self.current_value() - before
+    }
+}
+
+/// Advance every `GaugeAttribute` by the piped-in delta time (in seconds).
+pub fn advance_gauges(In(delta_seconds): In<f32>, mut query: Query<&mut GaugeAttribute>) {
+    for mut gauge in &mut query {
+        if gauge.rate_per_second != 0.0 {
+            gauge.advance(delta_seconds);
+        }
+    }
+}
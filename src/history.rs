@@ -0,0 +1,244 @@
+//! # Attribute History
+//!
+//! This module contains the implementation of the `AttributeHistory` component and the system that records it.
+//!
+//! An `AttributeHistory` samples an entity's `IntegerAttribute` at a fixed interval and keeps the samples compressed:
+//! each sample is stored as the difference from the one before it, and runs of identical differences are stored
+//! once with a count. A health value that sits at full for a minute, or drains at a steady rate, costs a single run.
+//!
+//! The history holds at most `capacity` samples. When it is full the oldest sample is dropped, so memory use is
+//! bounded regardless of how long the entity lives. This makes it suitable for kill-cam summaries and post-match
+//! graphs that only need the last few seconds or minutes.
+
+use std::collections::VecDeque;
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// A run of consecutive samples that all differ from their predecessor by the same amount.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct DeltaRun {
+    /// The difference between each sample in the run and the one before it.
+    delta: i64,
+    /// The number of samples in the run.
+    count: u32,
+}
+
+/// A bounded, delta and run-length compressed history of an attribute's value.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{AttributeHistory, IntegerAttribute};
+///
+/// // Sample every half second, keeping at most 20 samples (10 seconds).
+/// let mut history = AttributeHistory::new(0.5, 20);
+/// let mut health = IntegerAttribute::new(100);
+///
+/// for _ in 0..4 {
+///     history.record(&health, 0.5);
+///     health -= 10;
+/// }
+///
+/// assert_eq!(history.last_seconds(1.0), vec![80, 70]);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttributeHistory {
+    /// How often, in seconds, a sample is taken.
+    sample_interval: f32,
+    /// The maximum number of samples kept.
+    capacity: usize,
+    /// Time accumulated towards the next sample.
+    elapsed: f32,
+    /// The value of the oldest sample, if there are any samples.
+    first: Option<i32>,
+    /// The value of the newest sample, if there are any samples.
+    last: Option<i32>,
+    /// The differences between consecutive samples, oldest first.
+    runs: VecDeque<DeltaRun>,
+    /// The number of samples stored.
+    len: usize,
+}
+
+impl AttributeHistory {
+    /// Create an empty history that samples every `sample_interval` seconds and keeps at most `capacity` samples.
+    ///
+    /// A capacity of zero is treated as one.
+    #[must_use]
+    pub fn new(sample_interval: f32, capacity: usize) -> Self {
+        Self {
+            sample_interval,
+            capacity: capacity.max(1),
+            elapsed: 0.0,
+            first: None,
+            last: None,
+            runs: VecDeque::new(),
+            len: 0,
+        }
+    }
+
+    /// How often, in seconds, a sample is taken.
+    #[must_use]
+    pub const fn sample_interval(&self) -> f32 {
+        self.sample_interval
+    }
+
+    /// The maximum number of samples kept.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of samples stored.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if no samples have been stored.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of compressed runs stored. This is a measure of how much memory the history is using.
+    #[must_use]
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// The most recent sample, if there is one.
+    #[must_use]
+    pub const fn latest(&self) -> Option<i32> {
+        self.last
+    }
+
+    /// Advance the sampling clock by `delta_seconds`, taking a sample of `attribute` for every interval that passed.
+    ///
+    /// A sample interval of zero (or less) takes exactly one sample per call.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn record(&mut self, attribute: &IntegerAttribute, delta_seconds: f32) {
+        let value = attribute.current_value();
+
+        if self.sample_interval <= 0.0 {
+            self.push_sample(value);
+            return;
+        }
+
+        self.elapsed += delta_seconds;
+        let samples = (self.elapsed / self.sample_interval).floor();
+        self.elapsed -= samples * self.sample_interval;
+
+        // Anything beyond `capacity` identical samples would be dropped straight away, so don't push them.
+        // Float to integer casts saturate, so a huge delta is handled.
+        for _ in 0..(samples as usize).min(self.capacity) {
+            self.push_sample(value);
+        }
+    }
+
+    /// Add a sample directly, bypassing the sampling clock.
+    pub fn push_sample(&mut self, value: i32) {
+        let Some(last) = self.last else {
+            self.first = Some(value);
+            self.last = Some(value);
+            self.len = 1;
+            return;
+        };
+
+        let delta = i64::from(value) - i64::from(last);
+        match self.runs.back_mut() {
+            Some(run) if run.delta == delta && run.count < u32::MAX => run.count += 1,
+            _ => self.runs.push_back(DeltaRun { delta, count: 1 }),
+        }
+        self.last = Some(value);
+        self.len += 1;
+
+        while self.len > self.capacity {
+            self.drop_oldest();
+        }
+    }
+
+    /// Remove every sample, keeping the interval and capacity.
+    pub fn clear(&mut self) {
+        self.elapsed = 0.0;
+        self.first = None;
+        self.last = None;
+        self.runs.clear();
+        self.len = 0;
+    }
+
+    /// Decompress every stored sample, oldest first.
+    #[must_use]
+    pub fn samples(&self) -> Vec<i32> {
+        let Some(first) = self.first else {
+            return Vec::new();
+        };
+
+        let mut samples = Vec::with_capacity(self.len);
+        let mut value = i64::from(first);
+        samples.push(first);
+        for run in &self.runs {
+            for _ in 0..run.count {
+                value += run.delta;
+                // Every stored value started out as an `i32`, so this conversion cannot fail.
+                samples.push(i32::try_from(value).unwrap_or_default());
+            }
+        }
+
+        samples
+    }
+
+    /// Decompress the samples covering the last `seconds`, oldest first.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn last_seconds(&self, seconds: f32) -> Vec<i32> {
+        let wanted = if self.sample_interval <= 0.0 {
+            self.len
+        } else {
+            // Float to integer casts saturate, so negative or huge durations are handled.
+            (seconds / self.sample_interval).ceil() as usize
+        };
+
+        let samples = self.samples();
+        let skip = samples.len().saturating_sub(wanted);
+        samples[skip..].to_vec()
+    }
+
+    /// Drop the oldest sample, folding its difference into the new oldest value.
+    fn drop_oldest(&mut self) {
+        let Some(first) = self.first else {
+            return;
+        };
+
+        let Some(run) = self.runs.front_mut() else {
+            self.clear();
+            return;
+        };
+
+        // The next sample was stored as an `i32`, so this conversion cannot fail.
+        self.first = Some(i32::try_from(i64::from(first) + run.delta).unwrap_or_default());
+        run.count -= 1;
+        if run.count == 0 {
+            self.runs.pop_front();
+        }
+        self.len -= 1;
+    }
+}
+
+/// Record every `AttributeHistory` from the attribute `T` on the same entity, using the piped-in delta time (in
+/// seconds).
+pub fn record_history<T: AttributeComponent>(
+    In(delta_seconds): In<f32>,
+    mut query: Query<(&T, &mut AttributeHistory)>,
+) {
+    for (attribute, mut history) in &mut query {
+        history.record(attribute.attribute(), delta_seconds);
+    }
+}
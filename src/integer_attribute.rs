@@ -8,7 +8,7 @@
 use bevy_ecs::{component::Component, system::Resource};
 use serde::{Deserialize, Serialize};
 
-use crate::AttributeError;
+use crate::{AttributeError, OverflowPolicy};
 
 /// An integer attribute that can be used to represent things like health, mana, etc.
 ///
@@ -26,7 +26,20 @@ use crate::AttributeError;
 /// // Create a new attribute with a minimum value of 0, a maximum value of 100, and a current value of 100.
 /// let mut health = IntegerAttribute::new(100);
 /// ```
+///
+/// The constructors are `const`, so base values for static game data can be defined at compile time.
+///
+/// ```rust
+/// use nwest_shared_component_library::IntegerAttribute;
+///
+/// static BASE_GOBLIN_HEALTH: IntegerAttribute = IntegerAttribute::new(30);
+///
+/// let mut goblin_health = BASE_GOBLIN_HEALTH;
+/// goblin_health -= 10;
+/// assert_eq!(goblin_health, 20);
+/// ```
 #[derive(Serialize, Deserialize, Clone, Copy, Component, Resource, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IntegerAttribute {
     /// The maximum value of the attribute.
     ///
@@ -44,7 +57,7 @@ pub struct IntegerAttribute {
     pub min: i32,
     /// The current value of the attribute.
     ///
-    /// Clamped between `min` and `max`. This should usually be accessed through the `current_value` method, or implicitly, treating `IntegerAttribute` as an `i32`.
+    /// Clamped between `min` and `max`. This should usually be accessed through the `current_value` method, or implicitly through the `Deref<Target = i32>` implementation.
     pub current: i32,
 }
 
@@ -55,20 +68,25 @@ impl IntegerAttribute {
     ///
     /// If a negative maximum is provided, minimum will be clamped to the maximum value.
     #[must_use]
-    pub fn new(max: i32) -> Self {
+    pub const fn new(max: i32) -> Self {
         Self {
-            min: 0.clamp(i32::MIN, max),
+            min: if max < 0 { max } else { 0 },
             max,
             current: max,
         }
     }
 
+    /// Start building an attribute. See `IntegerAttributeBuilder`.
+    pub fn builder() -> IntegerAttributeBuilder {
+        IntegerAttributeBuilder::default()
+    }
+
     /// Create a new integer attribute with the given values.
     ///
     /// # Errors
     ///
     /// Returns an error if the minimum value is greater than the maximum value.
-    pub fn new_as_defined(min: i32, max: i32, current: i32) -> Result<Self, AttributeError> {
+    pub const fn new_as_defined(min: i32, max: i32, current: i32) -> Result<Self, AttributeError> {
         if min > max {
             return Err(AttributeError::MinGreaterThanMax(min, max));
         }
@@ -76,16 +94,20 @@ impl IntegerAttribute {
         Ok(Self {
             min,
             max,
-            current: current.clamp(min, max),
+            current: clamp(current, min, max),
         })
     }
 
-    /// Wrapper for `new_as_defined` that sets the current value to the maximum value.
+    /// Create a new integer attribute with the given values. Identical to `new_as_defined`.
     ///
     /// # Errors
     ///
     /// Returns an error if the minimum value is greater than the maximum value.
-    pub fn with_min_max_and_current(
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `IntegerAttribute::new_as_defined` instead"
+    )]
+    pub const fn with_min_max_and_current(
         min: i32,
         max: i32,
         current: i32,
@@ -100,7 +122,7 @@ impl IntegerAttribute {
     /// # Errors
     ///
     /// Returns an error if the minimum value is greater than the maximum value.
-    pub fn with_min_and_max(min: i32, max: i32) -> Result<Self, AttributeError> {
+    pub const fn with_min_and_max(min: i32, max: i32) -> Result<Self, AttributeError> {
         Self::new_as_defined(min, max, max)
     }
 
@@ -119,31 +141,29 @@ impl IntegerAttribute {
     /// // Set our current value to 5.
     /// mana.set_value(5);
     /// ```
-    pub fn set_value(&mut self, current: i32) {
-        self.current = current.clamp(self.min, self.max);
+    pub const fn set_value(&mut self, current: i32) {
+        self.current = clamp(current, self.min, self.max);
     }
 
     /// Get the current value of the attribute.
     ///
     /// This will be clamped between `min` and `max`.
     #[must_use]
-    pub fn current_value(&self) -> i32 {
-        self.current.clamp(self.min, self.max)
+    pub const fn current_value(&self) -> i32 {
+        clamp(self.current, self.min, self.max)
     }
 
     /// Get the percentage of the current value between the minimum and maximum values.
-    #[allow(clippy::cast_precision_loss)]
+    ///
+    /// An attribute whose minimum and maximum are equal is always full.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
     #[must_use]
     pub fn current_percentage(&self) -> f32 {
-        match self.min.cmp(&0) {
-            std::cmp::Ordering::Less => {
-                (self.current + self.min) as f32 / (self.max + self.min) as f32
-            }
-            std::cmp::Ordering::Equal => self.current as f32 / self.max as f32,
-            std::cmp::Ordering::Greater => {
-                (self.current - self.min) as f32 / (self.max - self.min) as f32
-            }
+        let range = i64::from(self.max) - i64::from(self.min);
+        if range == 0 {
+            return 1.0;
         }
+        ((i64::from(self.current_value()) - i64::from(self.min)) as f64 / range as f64) as f32
     }
 
     /// Set the max value of the attribute.
@@ -178,13 +198,13 @@ impl IntegerAttribute {
     /// assert_eq!(mana.current_value(), 5);
     /// assert_eq!(mana, 5);
     /// ```
-    pub fn set_max(&mut self, value: i32) -> Result<(), AttributeError> {
+    pub const fn set_max(&mut self, value: i32) -> Result<(), AttributeError> {
         if value < self.min {
             return Err(AttributeError::MaxLessThanMin(value, self.min));
         }
 
         self.max = value;
-        self.current = self.current.clamp(self.min, self.max);
+        self.current = clamp(self.current, self.min, self.max);
 
         Ok(())
     }
@@ -194,16 +214,198 @@ impl IntegerAttribute {
     /// # Errors
     ///
     /// Returns an error if the minimum value is greater than the maximum value.
-    pub fn set_min(&mut self, value: i32) -> Result<(), AttributeError> {
+    pub const fn set_min(&mut self, value: i32) -> Result<(), AttributeError> {
         if value > self.max {
             return Err(AttributeError::MinGreaterThanMax(value, self.max));
         }
 
         self.min = value;
-        self.current = self.current.clamp(self.min, self.max);
+        self.current = clamp(self.current, self.min, self.max);
+
+        Ok(())
+    }
+
+    /// Add to the current value, handling a result outside of `min` and `max` according to `policy`.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the policy is `OverflowPolicy::Error` and the result would be outside
+    /// of `min` and `max`. The attribute is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nwest_shared_component_library::{IntegerAttribute, OverflowPolicy};
+    ///
+    /// let mut health = IntegerAttribute::new(100);
+    /// assert_eq!(health.add_with_policy(-30, OverflowPolicy::Error), Ok(70));
+    /// assert!(health.add_with_policy(50, OverflowPolicy::Error).is_err());
+    /// assert_eq!(health.add_with_policy(50, OverflowPolicy::Clamp), Ok(100));
+    /// ```
+    pub fn add_with_policy(
+        &mut self,
+        rhs: i32,
+        policy: OverflowPolicy,
+    ) -> Result<i32, AttributeError> {
+        match policy {
+            OverflowPolicy::Clamp | OverflowPolicy::Saturate => *self += rhs,
+            OverflowPolicy::Error => {
+                self.current = self.check_bounds(i64::from(self.current) + i64::from(rhs))?;
+            }
+            OverflowPolicy::WrapAround => {
+                self.current = self.wrap(i64::from(self.current) + i64::from(rhs));
+            }
+        }
+
+        Ok(self.current)
+    }
+
+    /// Add to the current value, failing without any change if the result would be outside of `min` and `max`.
+    ///
+    /// Shorthand for `add_with_policy` with `OverflowPolicy::Error`. Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the result would be outside of `min` and `max`.
+    pub fn try_add(&mut self, rhs: i32) -> Result<i32, AttributeError> {
+        self.add_with_policy(rhs, OverflowPolicy::Error)
+    }
 
+    /// Subtract from the current value, failing without any change if the result would be outside of `min` and `max`.
+    ///
+    /// Returns the new current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the result would be outside of `min` and `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nwest_shared_component_library::IntegerAttribute;
+    ///
+    /// let mut mana = IntegerAttribute::new(10);
+    ///
+    /// // The spell can only be cast if the whole cost can be paid.
+    /// assert_eq!(mana.try_subtract(8), Ok(2));
+    /// assert!(mana.try_subtract(8).is_err());
+    /// assert_eq!(mana, 2);
+    /// ```
+    pub fn try_subtract(&mut self, rhs: i32) -> Result<i32, AttributeError> {
+        self.current = self.check_bounds(i64::from(self.current) - i64::from(rhs))?;
+        Ok(self.current)
+    }
+
+    /// Set the current value, failing without any change if it is outside of `min` and `max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::OutOfBounds` if the value is outside of `min` and `max`.
+    pub fn try_set_value(&mut self, current: i32) -> Result<(), AttributeError> {
+        self.current = self.check_bounds(i64::from(current))?;
         Ok(())
     }
+
+    /// Add to the current value, saturating at `min` and `max` even if the sum overflows an `i32`.
+    ///
+    /// The same as `add_with_policy` with `OverflowPolicy::Clamp` or `OverflowPolicy::Saturate`, and usable in `const`
+    /// functions. Returns the new current value.
+    pub const fn saturating_add(&mut self, rhs: i32) -> i32 {
+        self.set_value(self.current.saturating_add(rhs));
+        self.current
+    }
+
+    /// Wrap a value around into `min` to `max`, so that one past the maximum is the minimum.
+    pub(crate) fn wrap(&self, value: i64) -> i32 {
+        let (min, max) = (i64::from(self.min), i64::from(self.max));
+        let wrapped = min + (value - min).rem_euclid(max - min + 1);
+        // The value is between two `i32`s, so it fits.
+        i32::try_from(wrapped).unwrap_or(self.min)
+    }
+
+    /// Check that a value is between `min` and `max`.
+    fn check_bounds(&self, value: i64) -> Result<i32, AttributeError> {
+        let (min, max) = (i64::from(self.min), i64::from(self.max));
+        if value < min || value > max {
+            return Err(AttributeError::OutOfBounds(value, min, max));
+        }
+        // The value is between two `i32`s, so it fits.
+        i32::try_from(value).map_err(|_| AttributeError::OutOfBounds(value, min, max))
+    }
+}
+
+/// A builder for `IntegerAttribute`, for setting only the values that matter.
+///
+/// The minimum defaults to 0, or to the maximum if the maximum is negative, like `IntegerAttribute::new`. The maximum defaults
+/// to 0 and the current value defaults to the maximum.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::IntegerAttribute;
+///
+/// let mana = IntegerAttribute::builder().min(0).max(100).current(50).build().expect("Invalid attribute");
+/// assert_eq!(mana, 50);
+///
+/// // Unset values use the defaults.
+/// let stamina = IntegerAttribute::builder().max(20).build().expect("Invalid attribute");
+/// assert_eq!(stamina.min, 0);
+/// assert_eq!(stamina, 20);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[must_use]
+pub struct IntegerAttributeBuilder {
+    /// The minimum value, if set.
+    min: Option<i32>,
+    /// The maximum value.
+    max: i32,
+    /// The current value, if set.
+    current: Option<i32>,
+}
+
+impl IntegerAttributeBuilder {
+    /// Set the minimum value.
+    pub const fn min(mut self, min: i32) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value.
+    pub const fn max(mut self, max: i32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the current value. It will be clamped between the minimum and maximum.
+    pub const fn current(mut self, current: i32) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// Build the attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minimum value is greater than the maximum value.
+    pub fn build(self) -> Result<IntegerAttribute, AttributeError> {
+        let min = self.min.unwrap_or(if self.max < 0 { self.max } else { 0 });
+        let current = self.current.unwrap_or(self.max);
+        IntegerAttribute::new_as_defined(min, self.max, current)
+    }
+}
+
+/// `Ord::clamp` for `i32`, usable in `const` functions. Panics if `min > max`, like `Ord::clamp`.
+const fn clamp(value: i32, min: i32, max: i32) -> i32 {
+    assert!(min <= max, "min > max");
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
 }
 
 impl PartialEq for IntegerAttribute {
@@ -247,7 +449,7 @@ impl std::fmt::Debug for IntegerAttribute {
 
 impl std::fmt::Display for IntegerAttribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ({:.2}%)", self.current, self.current_percentage(),)
+        write!(f, "{} ({:.2}%)", self.current, self.current_percentage())
     }
 }
 
@@ -258,6 +460,32 @@ impl From<IntegerAttribute> for i32 {
     }
 }
 
+/// Allow an `IntegerAttribute` to be used wherever an `&i32` is expected, reading the current value.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::IntegerAttribute;
+///
+/// let health = IntegerAttribute::new(100);
+/// assert_eq!(*health, 100);
+/// assert!(health.is_positive());
+/// ```
+impl std::ops::Deref for IntegerAttribute {
+    type Target = i32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+/// Allow borrowing the current value of an `IntegerAttribute`.
+impl AsRef<i32> for IntegerAttribute {
+    fn as_ref(&self) -> &i32 {
+        &self.current
+    }
+}
+
 /// Allow conversion of `IntegerAttribute` to u32 (using `TryFrom`)
 ///
 /// # Errors
@@ -287,7 +515,7 @@ impl std::ops::Add<i32> for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (self.current.checked_add(rhs).unwrap_or(self.max)).clamp(self.min, self.max),
+            current: self.current.saturating_add(rhs).clamp(self.min, self.max),
         }
     }
 }
@@ -295,8 +523,7 @@ impl std::ops::Add<i32> for IntegerAttribute {
 /// Allow integer addition of `i32` and `IntegerAttribute` with assignment.
 impl std::ops::AddAssign<i32> for IntegerAttribute {
     fn add_assign(&mut self, rhs: i32) {
-        self.current =
-            (self.current.checked_add(rhs).unwrap_or(self.max)).clamp(self.min, self.max);
+        self.current = self.current.saturating_add(rhs).clamp(self.min, self.max);
     }
 }
 
@@ -308,7 +535,7 @@ impl std::ops::Sub<i32> for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (self.current.checked_sub(rhs).unwrap_or(self.min)).clamp(self.min, self.max),
+            current: self.current.saturating_sub(rhs).clamp(self.min, self.max),
         }
     }
 }
@@ -316,8 +543,7 @@ impl std::ops::Sub<i32> for IntegerAttribute {
 /// Allow integer subtraction of `i32` and `IntegerAttribute` with assignment.
 impl std::ops::SubAssign<i32> for IntegerAttribute {
     fn sub_assign(&mut self, rhs: i32) {
-        self.current =
-            (self.current.checked_sub(rhs).unwrap_or(self.min)).clamp(self.min, self.max);
+        self.current = self.current.saturating_sub(rhs).clamp(self.min, self.max);
     }
 }
 
@@ -329,7 +555,7 @@ impl std::ops::Mul<i32> for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (self.current * rhs).clamp(self.min, self.max),
+            current: self.current.saturating_mul(rhs).clamp(self.min, self.max),
         }
     }
 }
@@ -337,11 +563,13 @@ impl std::ops::Mul<i32> for IntegerAttribute {
 /// Allow multiplication of `i32` and `IntegerAttribute` with assignment.
 impl std::ops::MulAssign<i32> for IntegerAttribute {
     fn mul_assign(&mut self, rhs: i32) {
-        self.current = (self.current * rhs).clamp(self.min, self.max);
+        self.current = self.current.saturating_mul(rhs).clamp(self.min, self.max);
     }
 }
 
 /// Allow division of `IntegerAttribute` and `i32`.
+///
+/// Panics if `rhs` is 0, like integer division.
 impl std::ops::Div<i32> for IntegerAttribute {
     type Output = Self;
 
@@ -349,15 +577,17 @@ impl std::ops::Div<i32> for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (self.current / rhs).clamp(self.min, self.max),
+            current: self.current.saturating_div(rhs).clamp(self.min, self.max),
         }
     }
 }
 
 /// Allow division of `i32` and `IntegerAttribute` with assignment.
+///
+/// Panics if `rhs` is 0, like integer division.
 impl std::ops::DivAssign<i32> for IntegerAttribute {
     fn div_assign(&mut self, rhs: i32) {
-        self.current = (self.current / rhs).clamp(self.min, self.max);
+        self.current = self.current.saturating_div(rhs).clamp(self.min, self.max);
     }
 }
 
@@ -369,12 +599,14 @@ impl std::ops::Neg for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (-self.current).clamp(self.min, self.max),
+            current: self.current.saturating_neg().clamp(self.min, self.max),
         }
     }
 }
 
 /// Allow calculating remainder of `IntegerAttribute` and `i32`. This assigns the remainder as the current value.
+///
+/// Panics if `rhs` is 0, like integer remainder.
 impl std::ops::Rem<i32> for IntegerAttribute {
     type Output = Self;
 
@@ -382,15 +614,17 @@ impl std::ops::Rem<i32> for IntegerAttribute {
         Self {
             min: self.min,
             max: self.max,
-            current: (self.current % rhs).clamp(self.min, self.max),
+            current: self.current.wrapping_rem(rhs).clamp(self.min, self.max),
         }
     }
 }
 
 /// Allow calculating remainder of `i32` and `IntegerAttribute` with assignment.
+///
+/// Panics if `rhs` is 0, like integer remainder.
 impl std::ops::RemAssign<i32> for IntegerAttribute {
     fn rem_assign(&mut self, rhs: i32) {
-        self.current = (self.current % rhs).clamp(self.min, self.max);
+        self.current = self.current.wrapping_rem(rhs).clamp(self.min, self.max);
     }
 }
 
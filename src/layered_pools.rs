@@ -0,0 +1,210 @@
+//! # Layered Pools
+//!
+//! This module contains the implementation of the `LayeredPools` struct and its supporting types.
+//!
+//! `LayeredPools` composes an ordered list of `IntegerAttribute` pools (for example shield → armor → health) and drains
+//! incoming damage through them in order. Each layer can declare damage kinds that bypass it entirely, so that a
+//! lightning attack can skip the shield and go straight to armor.
+//!
+//! The damage kind is generic, so games can route damage with whatever type they already use to describe it.
+
+use bevy_ecs::component::Component;
+use serde::{Deserialize, Serialize};
+
+use crate::IntegerAttribute;
+
+/// A single layer of a `LayeredPools` stack.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{IntegerAttribute, PoolLayer};
+///
+/// // A shield layer that lightning damage passes straight through.
+/// let shield = PoolLayer::new("shield", IntegerAttribute::new(50)).bypassed_by("lightning");
+/// assert!(shield.is_bypassed_by(&"lightning"));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PoolLayer<K> {
+    /// The name of the layer, used to identify it in damage breakdowns.
+    pub name: String,
+    /// The pool of points this layer holds.
+    pub pool: IntegerAttribute,
+    /// The damage kinds that skip this layer entirely.
+    pub bypassed_by: Vec<K>,
+}
+
+impl<K: PartialEq> PoolLayer<K> {
+    /// Create a new layer with the given name and pool. No damage kinds bypass it.
+    #[must_use]
+    pub fn new(name: impl Into<String>, pool: IntegerAttribute) -> Self {
+        Self {
+            name: name.into(),
+            pool,
+            bypassed_by: Vec::new(),
+        }
+    }
+
+    /// Add a damage kind that bypasses this layer.
+    #[must_use]
+    pub fn bypassed_by(mut self, kind: K) -> Self {
+        self.bypassed_by.push(kind);
+        self
+    }
+
+    /// Check if the given damage kind bypasses this layer.
+    #[must_use]
+    pub fn is_bypassed_by(&self, kind: &K) -> bool {
+        self.bypassed_by.contains(kind)
+    }
+}
+
+/// How much of an instance of damage a single layer absorbed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LayerDamage {
+    /// The name of the layer.
+    pub name: String,
+    /// The amount of damage the layer absorbed.
+    pub absorbed: i32,
+    /// Whether the layer was skipped because the damage kind bypasses it.
+    pub bypassed: bool,
+}
+
+/// The per-layer result of applying damage to a `LayeredPools` stack.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DamageBreakdown {
+    /// The result for each layer, in drain order.
+    pub layers: Vec<LayerDamage>,
+    /// Damage that was left over after every layer was drained.
+    pub overflow: i32,
+}
+
+impl DamageBreakdown {
+    /// The total amount of damage absorbed across all layers.
+    #[must_use]
+    pub fn total_absorbed(&self) -> i32 {
+        self.layers.iter().map(|layer| layer.absorbed).sum()
+    }
+
+    /// The amount absorbed by the layer with the given name, if it exists.
+    #[must_use]
+    pub fn absorbed_by(&self, name: &str) -> Option<i32> {
+        self.layers
+            .iter()
+            .find(|layer| layer.name == name)
+            .map(|layer| layer.absorbed)
+    }
+}
+
+/// An ordered stack of pools that damage drains through, outermost layer first.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{IntegerAttribute, LayeredPools, PoolLayer};
+///
+/// let mut pools = LayeredPools::new()
+///     .with_layer(PoolLayer::new("shield", IntegerAttribute::new(20)).bypassed_by("lightning"))
+///     .with_layer(PoolLayer::new("armor", IntegerAttribute::new(30)))
+///     .with_layer(PoolLayer::new("health", IntegerAttribute::new(100)));
+///
+/// // Lightning skips the shield and is absorbed by armor first.
+/// let breakdown = pools.apply_damage(40, &"lightning");
+/// assert_eq!(breakdown.absorbed_by("shield"), Some(0));
+/// assert_eq!(breakdown.absorbed_by("armor"), Some(30));
+/// assert_eq!(breakdown.absorbed_by("health"), Some(10));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LayeredPools<K> {
+    /// The layers, in the order damage drains through them.
+    layers: Vec<PoolLayer<K>>,
+}
+
+impl<K> Default for LayeredPools<K> {
+    fn default() -> Self {
+        Self { layers: Vec::new() }
+    }
+}
+
+impl<K: PartialEq> LayeredPools<K> {
+    /// Create an empty stack of pools.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer beneath the existing layers.
+    #[must_use]
+    pub fn with_layer(mut self, layer: PoolLayer<K>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Add a layer beneath the existing layers.
+    pub fn push_layer(&mut self, layer: PoolLayer<K>) {
+        self.layers.push(layer);
+    }
+
+    /// Get the layers, in drain order.
+    #[must_use]
+    pub fn layers(&self) -> &[PoolLayer<K>] {
+        &self.layers
+    }
+
+    /// Get the layer with the given name.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&PoolLayer<K>> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+
+    /// Get the layer with the given name mutably.
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut PoolLayer<K>> {
+        self.layers.iter_mut().find(|layer| layer.name == name)
+    }
+
+    /// The sum of the current values of all layers.
+    #[must_use]
+    pub fn total(&self) -> i32 {
+        self.layers.iter().fold(0_i32, |total, layer| {
+            total.saturating_add(layer.pool.current_value())
+        })
+    }
+
+    /// Apply damage of the given kind, draining layers in order.
+    ///
+    /// Each layer absorbs as much as it can before reaching its minimum value, and the remainder moves on to the
+    /// next layer. Layers bypassed by `kind` are skipped. Damage that is zero or negative is ignored.
+    pub fn apply_damage(&mut self, amount: i32, kind: &K) -> DamageBreakdown {
+        let mut remaining = amount.max(0);
+        let mut breakdown = DamageBreakdown::default();
+
+        for layer in &mut self.layers {
+            let bypassed = layer.is_bypassed_by(kind);
+            let absorbed = if bypassed {
+                0
+            } else {
+                let available = layer
+                    .pool
+                    .current_value()
+                    .saturating_sub(layer.pool.min)
+                    .max(0);
+                remaining.min(available)
+            };
+
+            layer.pool -= absorbed;
+            remaining -= absorbed;
+            breakdown.layers.push(LayerDamage {
+                name: layer.name.clone(),
+                absorbed,
+                bypassed,
+            });
+        }
+
+        breakdown.overflow = remaining;
+        breakdown
+    }
+}
@@ -1,8 +1,78 @@
 //! Hello
 //!
 
+pub mod accumulator;
+pub mod achievements;
+pub mod attribute_changed;
+pub mod bounded_attribute;
+pub mod compat;
+pub mod cooldown;
+pub mod curves;
+pub mod depletion;
+#[cfg(feature = "dice")]
+pub mod dice;
+pub mod displayed_attribute;
+pub mod downed;
+pub mod editor_hints;
 pub mod errors;
+pub mod experience;
+pub mod fixed_decimal_attribute;
+pub mod float_attribute;
+pub mod gauge_attribute;
+pub mod history;
 pub mod integer_attribute;
+pub mod layered_pools;
+pub mod markers;
+pub mod offline;
+pub mod operation_queue;
+pub mod overflow;
+pub mod percent;
+pub mod periodic_reset;
+#[cfg(feature = "bevy_app")]
+pub mod plugin;
+pub mod prelude;
+pub mod regeneration;
+#[cfg(any(feature = "bevy_app", feature = "test-util"))]
+mod registration;
+pub mod replication;
+pub mod reputation;
+pub mod rollback;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod shielded_attribute;
+#[cfg(feature = "test-util")]
+pub mod test_harness;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod transaction;
+pub mod transfer;
+pub mod ui_edit;
+pub mod vitals;
+pub mod wallet;
+pub mod watermarks;
+pub mod wire;
 
+pub use attribute_changed::{AttributeChangeTracker, AttributeChanged};
+pub use bounded_attribute::BoundedAttribute;
+pub use cooldown::Cooldown;
+pub use depletion::{AttributeDepleted, Depletable, Depleted, DepletionPolicy};
+pub use displayed_attribute::DisplayedAttribute;
+pub use downed::{DownedEvent, DownedState, DownedTransition, LifeState, ReviveRequest};
 pub use errors::AttributeError;
-pub use integer_attribute::IntegerAttribute;
+pub use experience::{Level, LevelCurve};
+pub use fixed_decimal_attribute::{FixedDecimalAttribute, FixedDecimalAttributeBuilder};
+pub use float_attribute::{FloatAttribute, FloatAttributeBuilder};
+pub use gauge_attribute::GaugeAttribute;
+pub use history::AttributeHistory;
+pub use integer_attribute::{IntegerAttribute, IntegerAttributeBuilder};
+pub use layered_pools::{DamageBreakdown, LayerDamage, LayeredPools, PoolLayer};
+pub use operation_queue::{AttributeOperation, OperationQueue};
+pub use overflow::OverflowPolicy;
+pub use percent::Percent;
+pub use periodic_reset::{DayTick, PeriodicReset};
+pub use regeneration::Regeneration;
+pub use reputation::{Reputation, ReputationTier, ReputationTierChanged};
+pub use shielded_attribute::ShieldedAttribute;
+pub use vitals::{AttributeComponent, Health, Mana, Stamina, VitalsBundle};
+pub use wallet::{Transaction, Wallet, WalletTransaction};
+pub use watermarks::Watermarks;
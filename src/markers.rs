@@ -0,0 +1,104 @@
+//! # Threshold Markers
+//!
+//! This module contains marker components that reflect where an `IntegerAttribute` sits within its range, and the
+//! systems that keep them up to date.
+//!
+//! With the markers maintained, common checks become simple queries: `With<AttributeEmpty>` for death or out-of-mana,
+//! `With<AttributeBelow<25>>` for low-health warnings, and so on. The systems only look at attributes that changed,
+//! using Bevy change detection, so they are cheap to run every frame.
+//!
+//! `update_threshold_markers` is added by the `AttributesPlugin`. `update_below_marker` is generic over the
+//! percentage, so add one instance per threshold the game cares about.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Changed, Has},
+    system::{Commands, Query},
+};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// Present while the attribute is at its minimum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+pub struct AttributeEmpty;
+
+/// Present while the attribute is at its maximum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+pub struct AttributeFull;
+
+/// Present while the attribute's `current_percentage` is below `PCT` percent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+pub struct AttributeBelow<const PCT: u8>;
+
+impl<const PCT: u8> AttributeBelow<PCT> {
+    /// Check if an attribute is below the threshold.
+    #[must_use]
+    pub fn applies_to(attribute: &IntegerAttribute) -> bool {
+        attribute.current_percentage() < f32::from(PCT) / 100.0
+    }
+}
+
+/// The attributes `update_threshold_markers` looks at, and which markers they already have.
+type ThresholdQuery<'w, 's, T> =
+    Query<'w, 's, (Entity, &'static T, Has<AttributeEmpty>, Has<AttributeFull>), Changed<T>>;
+
+/// Insert or remove `AttributeEmpty` and `AttributeFull` on every entity whose attribute `T` changed.
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_threshold_markers<T: AttributeComponent>(
+    mut commands: Commands,
+    query: ThresholdQuery<T>,
+) {
+    for (entity, attribute, has_empty, has_full) in &query {
+        let attribute = attribute.attribute();
+        let value = attribute.current_value();
+        let empty = value == attribute.min;
+        let full = value == attribute.max;
+
+        if empty != has_empty {
+            if empty {
+                commands.entity(entity).insert(AttributeEmpty);
+            } else {
+                commands.entity(entity).remove::<AttributeEmpty>();
+            }
+        }
+        if full != has_full {
+            if full {
+                commands.entity(entity).insert(AttributeFull);
+            } else {
+                commands.entity(entity).remove::<AttributeFull>();
+            }
+        }
+    }
+}
+
+/// Insert or remove `AttributeBelow<PCT>` on every entity whose `IntegerAttribute` changed.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use nwest_shared_component_library::markers::update_below_marker;
+///
+/// let mut schedule = Schedule::default();
+/// // Maintain a low-health marker at 25%.
+/// schedule.add_systems(update_below_marker::<25>);
+/// schedule.run(&mut World::new());
+/// ```
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_below_marker<const PCT: u8>(
+    mut commands: Commands,
+    query: Query<(Entity, &IntegerAttribute, Has<AttributeBelow<PCT>>), Changed<IntegerAttribute>>,
+) {
+    for (entity, attribute, has_marker) in &query {
+        let below = AttributeBelow::<PCT>::applies_to(attribute);
+        if below == has_marker {
+            continue;
+        }
+        if below {
+            commands.entity(entity).insert(AttributeBelow::<PCT>);
+        } else {
+            commands.entity(entity).remove::<AttributeBelow<PCT>>();
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! # Offline Progression
+//!
+//! This module contains `simulate_elapsed`, which fast-forwards every time-based component in a `World` by a long
+//! duration, such as the time a game was closed.
+//!
+//! Every component is advanced in a single closed-form step rather than by running millions of ticks, so catching up
+//! on a week of offline time costs the same as one frame. The components are advanced in the same order the
+//! `AttributesPlugin` runs their systems. `PeriodicReset`s on the `DayTick` schedule are left alone, since only the
+//! game knows how many days passed; send that many `DayTick` events instead.
+
+use bevy_ecs::world::World;
+
+use crate::{
+    Cooldown, GaugeAttribute, IntegerAttribute, PeriodicReset, Regeneration, Reputation,
+    ShieldedAttribute,
+};
+
+/// Fast-forward every `Regeneration`, `ShieldedAttribute`, `Cooldown`, `GaugeAttribute`, `PeriodicReset`, and
+/// `Reputation` in the world by `seconds`.
+///
+/// Negative or non-finite durations are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::world::World;
+/// use nwest_shared_component_library::{offline::simulate_elapsed, IntegerAttribute, Regeneration};
+///
+/// let mut world = World::new();
+/// let mut energy = IntegerAttribute::new(100);
+/// energy.set_value(0);
+/// let entity = world.spawn((energy, Regeneration::new(1.0 / 60.0, 1.0))).id();
+///
+/// // An hour offline restores one point a minute.
+/// simulate_elapsed(&mut world, 3600.0);
+/// assert_eq!(world.get::<IntegerAttribute>(entity).map(|energy| energy.current), Some(60));
+/// ```
+pub fn simulate_elapsed(world: &mut World, seconds: f64) {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return;
+    }
+
+    let mut regeneration = world.query::<(&mut Regeneration, &mut IntegerAttribute)>();
+    for (mut regeneration, mut attribute) in regeneration.iter_mut(world) {
+        regeneration.simulate_elapsed(&mut attribute, seconds);
+    }
+
+    let mut shields = world.query::<&mut ShieldedAttribute>();
+    for mut shielded in shields.iter_mut(world) {
+        shielded.simulate_elapsed(seconds);
+    }
+
+    let mut cooldowns = world.query::<&mut Cooldown>();
+    for mut cooldown in cooldowns.iter_mut(world) {
+        cooldown.simulate_elapsed(seconds);
+    }
+
+    let mut gauges = world.query::<&mut GaugeAttribute>();
+    for mut gauge in gauges.iter_mut(world) {
+        gauge.simulate_elapsed(seconds);
+    }
+
+    let mut resets = world.query::<(&mut PeriodicReset, &mut IntegerAttribute)>();
+    for (mut reset, mut attribute) in resets.iter_mut(world) {
+        reset.simulate_elapsed(&mut attribute, seconds);
+    }
+
+    let mut reputations = world.query::<&mut Reputation>();
+    for mut reputation in reputations.iter_mut(world) {
+        reputation.simulate_elapsed(seconds);
+    }
+}
@@ -0,0 +1,199 @@
+//! # Operation Queue
+//!
+//! This module contains the implementation of the `OperationQueue` component and the system that applies it.
+//!
+//! When several systems change the same attribute in one tick, the result normally depends on the order they happen
+//! to run in. Queueing the changes instead, and applying them all at once, makes the outcome depend only on which
+//! operations were queued. The rules are:
+//!
+//! 1. Of the set operations (`Set`, `SetToMax`, `SetToMin`), the one with the highest priority wins. Ties go to the
+//!    one that results in the larger value. If there is no set, the attribute's current value is the starting point.
+//! 2. Add operations with a lower priority than the winning set are overridden by it and discarded. All other adds
+//!    are summed and applied on top of the starting point.
+//! 3. The result is clamped to the attribute's `min` and `max` once, at the end.
+//!
+//! So "set to 50, add 10, set to max" at equal priority resolves to the maximum, then adds 10, then clamps.
+
+use bevy_ecs::{component::Component, system::Query};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// An operation on an `IntegerAttribute`'s current value.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AttributeOperation {
+    /// Set the current value.
+    Set(i32),
+    /// Set the current value to the maximum.
+    SetToMax,
+    /// Set the current value to the minimum.
+    SetToMin,
+    /// Add to the current value. Negative amounts subtract.
+    Add(i32),
+}
+
+/// An operation waiting in an `OperationQueue`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueuedOperation {
+    /// The operation.
+    pub operation: AttributeOperation,
+    /// The priority used to resolve conflicts. Higher wins.
+    pub priority: i32,
+}
+
+/// Operations on an entity's `IntegerAttribute`, applied together in an order-independent way.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{operation_queue::OperationQueue, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// health.set_value(20);
+///
+/// let mut queue = OperationQueue::default();
+/// queue.add(10);
+/// queue.set_to_max();
+/// queue.add(-15);
+///
+/// assert!(queue.apply(&mut health));
+/// assert_eq!(health, 95);
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OperationQueue {
+    /// The queued operations, in the order they were queued. The order does not affect the result.
+    operations: Vec<QueuedOperation>,
+}
+
+impl OperationQueue {
+    /// Create an empty queue.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            operations: Vec::new(),
+        }
+    }
+
+    /// Queue an operation with a priority.
+    pub fn push(&mut self, operation: AttributeOperation, priority: i32) {
+        self.operations.push(QueuedOperation {
+            operation,
+            priority,
+        });
+    }
+
+    /// Queue setting the current value, with priority 0.
+    pub fn set(&mut self, value: i32) {
+        self.push(AttributeOperation::Set(value), 0);
+    }
+
+    /// Queue setting the current value to the maximum, with priority 0.
+    pub fn set_to_max(&mut self) {
+        self.push(AttributeOperation::SetToMax, 0);
+    }
+
+    /// Queue setting the current value to the minimum, with priority 0.
+    pub fn set_to_min(&mut self) {
+        self.push(AttributeOperation::SetToMin, 0);
+    }
+
+    /// Queue adding to the current value, with priority 0.
+    pub fn add(&mut self, amount: i32) {
+        self.push(AttributeOperation::Add(amount), 0);
+    }
+
+    /// The queued operations, in the order they were queued.
+    #[must_use]
+    pub fn operations(&self) -> &[QueuedOperation] {
+        &self.operations
+    }
+
+    /// The number of queued operations.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Check if there are no queued operations.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Discard every queued operation.
+    pub fn clear(&mut self) {
+        self.operations.clear();
+    }
+
+    /// The value the attribute would have after applying the queue, without changing anything.
+    #[must_use]
+    pub fn resolve(&self, attribute: &IntegerAttribute) -> i32 {
+        let winner = self
+            .operations
+            .iter()
+            .filter_map(|queued| {
+                let value = match queued.operation {
+                    AttributeOperation::Set(value) => value,
+                    AttributeOperation::SetToMax => attribute.max,
+                    AttributeOperation::SetToMin => attribute.min,
+                    AttributeOperation::Add(_) => return None,
+                };
+                Some((queued.priority, value))
+            })
+            .max();
+
+        let (start, floor) = winner.map_or_else(
+            || (attribute.current_value(), i32::MIN),
+            |(priority, value)| (value, priority),
+        );
+
+        let added: i64 = self
+            .operations
+            .iter()
+            .filter(|queued| queued.priority >= floor)
+            .filter_map(|queued| match queued.operation {
+                AttributeOperation::Add(amount) => Some(i64::from(amount)),
+                _ => None,
+            })
+            .sum();
+
+        let total =
+            (i64::from(start) + added).clamp(i64::from(attribute.min), i64::from(attribute.max));
+        // Clamped to an i32 range above, so this can't fail.
+        i32::try_from(total).unwrap_or(attribute.max)
+    }
+
+    /// Apply the queued operations to the attribute and empty the queue.
+    ///
+    /// Returns whether the attribute's current value changed.
+    pub fn apply(&mut self, attribute: &mut IntegerAttribute) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let before = attribute.current_value();
+        attribute.set_value(self.resolve(attribute));
+        self.clear();
+        attribute.current_value() != before
+    }
+}
+
+/// Apply and empty every non-empty `OperationQueue` to the attribute `T` on the same entity.
+pub fn apply_operation_queues<T: AttributeComponent>(
+    mut query: Query<(&mut OperationQueue, &mut T)>,
+) {
+    for (mut queue, mut attribute) in &mut query {
+        if queue.is_empty() {
+            continue;
+        }
+        let value = queue.resolve(attribute.attribute());
+        queue.clear();
+        if attribute.attribute().current_value() != value {
+            attribute.attribute_mut().set_value(value);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! # Overflow Policy
+//!
+//! This module contains the `OverflowPolicy` enum, which chooses what happens when arithmetic would take an attribute
+//! past its minimum or maximum.
+//!
+//! The arithmetic operators on attributes always clamp. Gameplay code that needs to know when a change didn't fit can
+//! pass a different policy to `add_with_policy`, or use the `try_add` and `saturating_add` shorthands. To configure
+//! this once per attribute instead, wrap it in a `BoundedAttribute`.
+
+use serde::{Deserialize, Serialize};
+
+/// What happens when arithmetic would take an attribute past its minimum or maximum.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OverflowPolicy {
+    /// The result is clamped to the minimum and maximum, the same as the arithmetic operators.
+    ///
+    /// The arithmetic saturates at the limits of the underlying integer type before clamping, so a change too large for
+    /// the integer type still ends at the bound it was moving towards.
+    #[default]
+    Clamp,
+    /// The same as `Clamp`.
+    ///
+    /// This once differed because the arithmetic operators could overflow. They saturate now, so the two policies are
+    /// equivalent. It is kept so that existing code and saved data keep working.
+    Saturate,
+    /// The attribute is left unchanged and `AttributeError::OutOfBounds` is returned.
+    Error,
+    /// The result wraps around, so going one past the maximum gives the minimum and one below the minimum gives the
+    /// maximum. Suits cyclic values such as angles or the time of day.
+    WrapAround,
+}
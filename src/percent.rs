@@ -0,0 +1,119 @@
+//! # Percent
+//!
+//! This module contains the `Percent` type, a fraction between 0.0 and 1.0 (inclusive) that is validated when it is
+//! created.
+//!
+//! Percentages passed around as loose floats can silently go above 100% or below 0%. A `Percent` can't, and it
+//! formats itself the way a player expects to read it (`25%`, `12.5%`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::AttributeError;
+
+/// A fraction between 0.0 (0%) and 1.0 (100%), inclusive.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::Percent;
+///
+/// let chance = Percent::new(0.125).expect("Invalid percent");
+/// assert_eq!(chance.to_string(), "12.5%");
+/// assert_eq!(chance.complement().to_string(), "87.5%");
+///
+/// // Values outside 0.0 to 1.0 are rejected.
+/// assert!(Percent::new(1.5).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Percent(f64);
+
+impl Percent {
+    /// 0%.
+    pub const ZERO: Self = Self(0.0);
+    /// 100%.
+    pub const FULL: Self = Self(1.0);
+
+    /// Create a percent from a fraction between 0.0 and 1.0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fraction is outside 0.0 to 1.0, or is NaN.
+    pub fn new(fraction: f64) -> Result<Self, AttributeError> {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(AttributeError::ConversionError(format!(
+                "Percent {fraction} is not between 0.0 and 1.0."
+            )));
+        }
+        Ok(Self(fraction))
+    }
+
+    /// Create a percent from a whole-number percentage, such as 25 for 25%.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the percentage is outside 0 to 100, or is NaN.
+    pub fn from_percentage(percentage: f64) -> Result<Self, AttributeError> {
+        Self::new(percentage / 100.0)
+    }
+
+    /// Create a percent from a fraction, clamping it to 0.0 to 1.0. NaN becomes 0%.
+    #[must_use]
+    pub const fn clamped(fraction: f64) -> Self {
+        if fraction.is_nan() {
+            Self::ZERO
+        } else {
+            Self(fraction.clamp(0.0, 1.0))
+        }
+    }
+
+    /// The fraction, between 0.0 and 1.0.
+    #[must_use]
+    pub const fn fraction(&self) -> f64 {
+        self.0
+    }
+
+    /// The percentage, between 0.0 and 100.0.
+    #[must_use]
+    pub const fn percentage(&self) -> f64 {
+        self.0 * 100.0
+    }
+
+    /// The remaining percent, i.e. `100% - self`.
+    #[must_use]
+    pub const fn complement(&self) -> Self {
+        Self(1.0 - self.0)
+    }
+
+    /// This percent of `value`, rounded to the nearest integer (half away from zero).
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn of(&self, value: i32) -> i32 {
+        // The result is between 0 and `value`, so it always fits in an `i32`.
+        (f64::from(value) * self.0).round() as i32
+    }
+}
+
+impl TryFrom<f64> for Percent {
+    type Error = AttributeError;
+
+    fn try_from(fraction: f64) -> Result<Self, Self::Error> {
+        Self::new(fraction)
+    }
+}
+
+impl From<Percent> for f64 {
+    fn from(percent: Percent) -> Self {
+        percent.0
+    }
+}
+
+impl std::fmt::Display for Percent {
+    /// Format as a percentage with at most two decimals, dropping trailing zeros.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatted = format!("{:.2}", self.percentage());
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+        write!(f, "{trimmed}%")
+    }
+}
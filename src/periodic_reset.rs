@@ -0,0 +1,193 @@
+//! # Periodic Reset
+//!
+//! This module contains the implementation of the `PeriodicReset` component, the `DayTick` event, and the systems
+//! that reset attributes on a schedule.
+//!
+//! A `PeriodicReset` resets the `IntegerAttribute` on the same entity, either to its maximum or to a fixed value. It
+//! runs on a `ResetSchedule`: every fixed interval of seconds, or whenever the game sends a `DayTick` event. This
+//! covers energy systems, daily-use charges, and anything else that refills on a calendar rather than continuously.
+//!
+//! The time since the last reset is serialized with the component. To handle time that passed while the game was
+//! closed, call `simulate_elapsed` with the offline seconds after loading.
+
+use bevy_ecs::{
+    component::Component,
+    event::{Event, EventReader},
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// When a `PeriodicReset` happens.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ResetSchedule {
+    /// Every this many seconds. Intervals that aren't positive never reset.
+    Interval(f64),
+    /// Whenever a `DayTick` event is sent.
+    DayTick,
+}
+
+/// What a `PeriodicReset` sets the attribute to.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ResetTarget {
+    /// The attribute's maximum.
+    #[default]
+    Max,
+    /// A fixed value, clamped to the attribute's range.
+    Value(i32),
+}
+
+/// An event telling every `PeriodicReset` on the `DayTick` schedule to reset, e.g. at the start of an in-game or
+/// real-world day.
+#[derive(Event, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DayTick;
+
+/// Resets the `IntegerAttribute` on the same entity on a schedule.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     periodic_reset::{PeriodicReset, ResetTarget},
+///     IntegerAttribute,
+/// };
+///
+/// // Energy that refills every hour.
+/// let mut energy = IntegerAttribute::new(5);
+/// let mut reset = PeriodicReset::every(3600.0, ResetTarget::Max);
+/// energy -= 5;
+///
+/// // The game was closed for two and a half hours.
+/// assert_eq!(reset.tick(9000.0), 2);
+/// reset.apply_to(&mut energy);
+/// assert_eq!(energy, 5);
+/// assert_eq!(reset.time_until_reset(), Some(1800.0));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeriodicReset {
+    /// When the reset happens.
+    pub schedule: ResetSchedule,
+    /// What the attribute is reset to.
+    pub target: ResetTarget,
+    /// Seconds since the last reset. Only used by the `Interval` schedule. Kept as `f64` so that long intervals don't
+    /// lose small frame deltas.
+    elapsed: f64,
+}
+
+impl PeriodicReset {
+    /// Create a reset with the given schedule and target.
+    #[must_use]
+    pub const fn new(schedule: ResetSchedule, target: ResetTarget) -> Self {
+        Self {
+            schedule,
+            target,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Create a reset that happens every `interval_seconds`.
+    #[must_use]
+    pub const fn every(interval_seconds: f64, target: ResetTarget) -> Self {
+        Self::new(ResetSchedule::Interval(interval_seconds), target)
+    }
+
+    /// Create a reset that happens on every `DayTick` event.
+    #[must_use]
+    pub const fn on_day_tick(target: ResetTarget) -> Self {
+        Self::new(ResetSchedule::DayTick, target)
+    }
+
+    /// Seconds since the last reset. Always zero for the `DayTick` schedule.
+    #[must_use]
+    pub const fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    /// Seconds until the next reset, or `None` if the schedule isn't a positive interval.
+    #[must_use]
+    pub fn time_until_reset(&self) -> Option<f64> {
+        match self.schedule {
+            ResetSchedule::Interval(interval) if interval > 0.0 => Some(interval - self.elapsed),
+            _ => None,
+        }
+    }
+
+    /// Advance the interval by `delta_seconds`, which may be hours of offline time.
+    ///
+    /// Returns how many resets were due. Resetting is idempotent, so `apply_to` only needs to be called once however
+    /// many were due. Does nothing for the `DayTick` schedule, or for negative or non-finite deltas.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn tick(&mut self, delta_seconds: f64) -> u64 {
+        let ResetSchedule::Interval(interval) = self.schedule else {
+            return 0;
+        };
+        if interval <= 0.0 || !delta_seconds.is_finite() || delta_seconds <= 0.0 {
+            return 0;
+        }
+
+        self.elapsed += delta_seconds;
+        if self.elapsed < interval {
+            return 0;
+        }
+
+        let due = (self.elapsed / interval).floor();
+        self.elapsed = interval.mul_add(-due, self.elapsed).max(0.0);
+        // Float to integer casts saturate, so a huge delta is handled.
+        due as u64
+    }
+
+    /// Fast-forward the interval by `seconds`, such as the time a game was closed, resetting the attribute if any
+    /// resets were due.
+    ///
+    /// Returns how many resets were due. Does nothing for the `DayTick` schedule.
+    pub fn simulate_elapsed(&mut self, attribute: &mut IntegerAttribute, seconds: f64) -> u64 {
+        let due = self.tick(seconds);
+        if due > 0 {
+            self.apply_to(attribute);
+        }
+        due
+    }
+
+    /// Reset the attribute to the target.
+    pub const fn apply_to(&self, attribute: &mut IntegerAttribute) {
+        match self.target {
+            ResetTarget::Max => attribute.set_value(attribute.max),
+            ResetTarget::Value(value) => attribute.set_value(value),
+        }
+    }
+}
+
+/// Advance every interval `PeriodicReset` by the piped-in delta time (in seconds), resetting the attributes that are
+/// due.
+pub fn tick_periodic_resets<T: AttributeComponent>(
+    In(delta_seconds): In<f32>,
+    mut query: Query<(&mut PeriodicReset, &mut T)>,
+) {
+    for (mut reset, mut attribute) in &mut query {
+        if !matches!(reset.schedule, ResetSchedule::Interval(_)) {
+            continue;
+        }
+        if reset.tick(f64::from(delta_seconds)) > 0 {
+            reset.apply_to(attribute.attribute_mut());
+        }
+    }
+}
+
+/// Reset the attribute of every `PeriodicReset` on the `DayTick` schedule, once for any number of `DayTick` events.
+pub fn apply_day_ticks<T: AttributeComponent>(
+    mut ticks: EventReader<DayTick>,
+    mut query: Query<(&PeriodicReset, &mut T)>,
+) {
+    if ticks.read().count() == 0 {
+        return;
+    }
+    for (reset, mut attribute) in &mut query {
+        if reset.schedule == ResetSchedule::DayTick {
+            reset.apply_to(attribute.attribute_mut());
+        }
+    }
+}
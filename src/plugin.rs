@@ -0,0 +1,47 @@
+//! # Attributes Plugin
+//!
+//! This module contains the `AttributesPlugin`, which wires the library's events and systems into a Bevy `App`.
+//!
+//! It is only available with the `bevy_app` feature. The systems run in the `Update` schedule, grouped into the public
+//! `AttributeSystems` sets so that consumers can order their own systems around them.
+//!
+//! The time-based systems read their delta time from `Time`, so the app needs `TimePlugin` (included in both
+//! `MinimalPlugins` and `DefaultPlugins`).
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::system::Res;
+use bevy_time::Time;
+
+use crate::registration::register_attribute_systems;
+pub use crate::registration::AttributeSystems;
+
+/// Registers the library's events and systems with a Bevy `App`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_app::App;
+/// use bevy_time::TimePlugin;
+/// use nwest_shared_component_library::plugin::AttributesPlugin;
+///
+/// let mut app = App::new();
+/// app.add_plugins((TimePlugin, AttributesPlugin));
+/// app.update();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AttributesPlugin;
+
+impl Plugin for AttributesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(Update);
+        app.world_mut().schedule_scope(Update, |world, schedule| {
+            register_attribute_systems(world, schedule, delta_seconds);
+        });
+    }
+}
+
+/// Read the time since the last update, in seconds, for piping into the time-based systems.
+#[allow(clippy::needless_pass_by_value)]
+fn delta_seconds(time: Res<Time>) -> f32 {
+    time.delta_seconds()
+}
@@ -0,0 +1,43 @@
+//! # Prelude
+//!
+//! The commonly used types and traits of the library, for glob importing.
+//!
+//! ```rust
+//! use nwest_shared_component_library::prelude::*;
+//!
+//! let mut health = IntegerAttribute::new(100);
+//! health -= 10;
+//! assert_eq!(health, 90);
+//! ```
+//!
+//! Systems and less common helpers are left out; import them from their modules.
+
+pub use crate::{
+    AttributeChangeTracker, AttributeChanged, AttributeComponent, AttributeDepleted,
+    AttributeError, AttributeHistory, BoundedAttribute, Cooldown, DamageBreakdown, DayTick,
+    Depletable, Depleted, DisplayedAttribute, DownedEvent, DownedState, DownedTransition,
+    FixedDecimalAttribute, FloatAttribute, GaugeAttribute, Health, IntegerAttribute, LayeredPools,
+    Level, LevelCurve, LifeState, Mana, OperationQueue, OverflowPolicy, Percent, PeriodicReset,
+    PoolLayer, Regeneration, Reputation, ReputationTier, ReputationTierChanged, ReviveRequest,
+    ShieldedAttribute, Stamina, Transaction, VitalsBundle, Wallet, WalletTransaction, Watermarks,
+};
+
+pub use crate::accumulator::{Accumulator, KahanAccumulator};
+pub use crate::achievements::{
+    AchievementCondition, AchievementContext, AchievementUnlocked, Achievements,
+};
+pub use crate::curves::ScalingCurve;
+pub use crate::editor_hints::{EditorHint, EditorHints};
+pub use crate::markers::{AttributeBelow, AttributeEmpty, AttributeFull};
+pub use crate::rollback::{RollbackBuffer, Snapshot};
+pub use crate::transaction::StatTransaction;
+pub use crate::transfer::{convert_between, transfer, TransferPolicy};
+pub use crate::ui_edit::{AttributeField, EditPolicy, UiEdit};
+pub use crate::wallet::ExchangeRate;
+pub use crate::wire::WireFormat;
+
+#[cfg(feature = "dice")]
+pub use crate::dice::Roll;
+
+#[cfg(feature = "bevy_app")]
+pub use crate::plugin::{AttributeSystems, AttributesPlugin};
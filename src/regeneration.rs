@@ -0,0 +1,123 @@
+//! # Regeneration
+//!
+//! This module contains the implementation of the `Regeneration` component and the system that applies it.
+//!
+//! A `Regeneration` restores (or, with a negative rate, drains) an entity's `IntegerAttribute` over time. Amounts are
+//! applied once per tick interval, and fractional points are carried over between ticks so that slow rates are not
+//! lost to rounding. All changes go through the attribute's normal arithmetic, so they are clamped to its min and max.
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// Regenerates an attribute at a fixed rate per second, applied once per tick interval.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{IntegerAttribute, Regeneration};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// health -= 50;
+///
+/// // Regenerate 2 points per second, applied once every second.
+/// let mut regeneration = Regeneration::new(2.0, 1.0);
+///
+/// regeneration.tick(&mut health, 0.5);
+/// assert_eq!(health, 50);
+///
+/// regeneration.tick(&mut health, 0.5);
+/// assert_eq!(health, 52);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Regeneration {
+    /// The amount regenerated per second. Negative values drain the attribute instead.
+    pub rate_per_second: f32,
+    /// How often, in seconds, the regenerated amount is applied.
+    ///
+    /// A value of zero (or less) applies regeneration on every tick.
+    pub tick_interval: f32,
+    /// Time accumulated towards the next tick.
+    elapsed: f32,
+    /// Fractional points carried over from previous ticks.
+    carry: f32,
+}
+
+impl Regeneration {
+    /// Create a new regeneration with the given rate per second and tick interval (in seconds).
+    #[must_use]
+    pub const fn new(rate_per_second: f32, tick_interval: f32) -> Self {
+        Self {
+            rate_per_second,
+            tick_interval,
+            elapsed: 0.0,
+            carry: 0.0,
+        }
+    }
+
+    /// Advance the regeneration by `delta_seconds`, applying any whole points that are due to `attribute`.
+    ///
+    /// Returns the amount the attribute actually changed by, after clamping.
+    pub fn tick(&mut self, attribute: &mut IntegerAttribute, delta_seconds: f32) -> i32 {
+        self.simulate_elapsed(attribute, f64::from(delta_seconds))
+    }
+
+    /// Fast-forward the regeneration by `seconds`, such as the time a game was closed, in a single step.
+    ///
+    /// This is `tick` computed in `f64`, so that days of offline time keep their fractions. Negative or non-finite
+    /// durations are ignored. Returns the amount the attribute actually changed by, after clamping.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn simulate_elapsed(&mut self, attribute: &mut IntegerAttribute, seconds: f64) -> i32 {
+        if !seconds.is_finite() || seconds < 0.0 {
+            return 0;
+        }
+
+        let elapsed = f64::from(self.elapsed) + seconds;
+        let interval = f64::from(self.tick_interval);
+        let applied = if interval <= 0.0 {
+            elapsed
+        } else {
+            (elapsed / interval).floor() * interval
+        };
+        self.elapsed = (elapsed - applied) as f32;
+
+        if applied <= 0.0 {
+            return 0;
+        }
+
+        let amount = f64::from(self.rate_per_second).mul_add(applied, f64::from(self.carry));
+        let whole = amount.trunc();
+        self.carry = (amount - whole) as f32;
+
+        let before = attribute.current_value();
+        // Float to integer casts saturate, so a huge amount is handled.
+        attribute.set_value((f64::from(before) + whole) as i32);
+        attribute.current_value().saturating_sub(before)
+    }
+}
+
+/// Apply every `Regeneration` to the attribute `T` on the same entity, using the piped-in delta time (in seconds).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use nwest_shared_component_library::{regeneration::regenerate, vitals::Mana};
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((|| 0.016_f32).pipe(regenerate::<Mana>));
+/// schedule.run(&mut World::new());
+/// ```
+pub fn regenerate<T: AttributeComponent>(
+    In(delta_seconds): In<f32>,
+    mut query: Query<(&mut Regeneration, &mut T)>,
+) {
+    for (mut regeneration, mut attribute) in &mut query {
+        regeneration.tick(attribute.attribute_mut(), delta_seconds);
+    }
+}
@@ -0,0 +1,149 @@
+//! # Registration
+//!
+//! This module contains the list of the library's events and systems, shared by the `AttributesPlugin` and the
+//! `TestHarness` so that both always run the same systems in the same order.
+//!
+//! The time-based systems read their delta time from whichever system is passed in: the plugin reads `Time`, and the
+//! harness reads its fixed tick length.
+//!
+//! The systems that work on an attribute are added once for bare `IntegerAttribute`s and once for `Health`.
+
+use bevy_ecs::{
+    event::{Event, EventRegistry, Events},
+    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, Schedule, SystemSet},
+    system::IntoSystem,
+    world::World,
+};
+
+use crate::{
+    achievements::{evaluate_achievements, AchievementUnlocked},
+    attribute_changed::emit_attribute_changes,
+    cooldown::tick_cooldowns,
+    depletion::update_depletion,
+    displayed_attribute::animate_displayed_attributes,
+    downed::{process_revive_requests, update_downed_state},
+    gauge_attribute::advance_gauges,
+    history::record_history,
+    markers::update_threshold_markers,
+    operation_queue::apply_operation_queues,
+    periodic_reset::{apply_day_ticks, tick_periodic_resets},
+    regeneration::regenerate,
+    reputation::{decay_reputation, emit_reputation_changes},
+    shielded_attribute::decay_shields,
+    wallet::emit_wallet_transactions,
+    watermarks::update_watermarks,
+    AttributeChanged, AttributeComponent, AttributeDepleted, DayTick, DownedEvent, Health,
+    IntegerAttribute, ReputationTierChanged, ReviveRequest, WalletTransaction,
+};
+
+/// The system sets the library's systems are added to. They run in the order listed.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeSystems {
+    /// Applies queued `OperationQueue` operations, so the rest of the tick sees their result.
+    Operations,
+    /// Applies `PeriodicReset`s and then `Regeneration` to attributes, decays `ShieldedAttribute` shields, recharges
+    /// `Cooldown`s, and advances `GaugeAttribute`s.
+    Regeneration,
+    /// Advances `DownedState`, processes `ReviveRequest` events, and updates the `Depleted` marker of `Depletable`
+    /// attributes.
+    DownedState,
+    /// Sends `WalletTransaction` events for recorded wallet transactions.
+    Wallet,
+    /// Decays `Reputation` standings and sends `ReputationTierChanged` events.
+    Reputation,
+    /// Sends `AttributeChanged` events for tracked attributes, updates the threshold markers and `Watermarks`, checks
+    /// `Achievements`, animates `DisplayedAttribute`s, and records `AttributeHistory`. Runs last so it sees every change
+    /// made above.
+    Changes,
+}
+
+/// Register the library's events in `world` and add its systems to `schedule`.
+///
+/// `delta_seconds` is piped into the time-based systems.
+pub fn register_attribute_systems<M>(
+    world: &mut World,
+    schedule: &mut Schedule,
+    delta_seconds: impl IntoSystem<(), f32, M> + Copy,
+) {
+    register_event::<DownedEvent>(world);
+    register_event::<ReviveRequest>(world);
+    register_event::<WalletTransaction>(world);
+    register_event::<ReputationTierChanged>(world);
+    register_event::<AttributeChanged>(world);
+    register_event::<AchievementUnlocked>(world);
+    register_event::<DayTick>(world);
+    register_event::<AttributeDepleted>(world);
+
+    schedule
+        .configure_sets(
+            (
+                AttributeSystems::Operations,
+                AttributeSystems::Regeneration,
+                AttributeSystems::DownedState,
+                AttributeSystems::Wallet,
+                AttributeSystems::Reputation,
+                AttributeSystems::Changes,
+            )
+                .chain(),
+        )
+        .add_systems((
+            (
+                delta_seconds.pipe(decay_shields),
+                delta_seconds.pipe(tick_cooldowns),
+                delta_seconds.pipe(advance_gauges),
+            )
+                .in_set(AttributeSystems::Regeneration),
+            emit_wallet_transactions.in_set(AttributeSystems::Wallet),
+            (
+                delta_seconds.pipe(decay_reputation),
+                emit_reputation_changes,
+            )
+                .chain()
+                .in_set(AttributeSystems::Reputation),
+            evaluate_achievements.in_set(AttributeSystems::Changes),
+        ));
+
+    register_attribute_component::<IntegerAttribute, M>(schedule, delta_seconds);
+    register_attribute_component::<Health, M>(schedule, delta_seconds);
+}
+
+/// Add the systems that work on an `AttributeComponent` to `schedule`, for the attribute `T`.
+fn register_attribute_component<T: AttributeComponent, M>(
+    schedule: &mut Schedule,
+    delta_seconds: impl IntoSystem<(), f32, M> + Copy,
+) {
+    schedule.add_systems((
+        apply_operation_queues::<T>.in_set(AttributeSystems::Operations),
+        // These all change the attribute, so they run in a fixed order: resets land first, then the frame's
+        // regeneration is applied on top.
+        (
+            delta_seconds.pipe(tick_periodic_resets::<T>),
+            apply_day_ticks::<T>,
+            delta_seconds.pipe(regenerate::<T>),
+        )
+            .chain()
+            .in_set(AttributeSystems::Regeneration),
+        (
+            delta_seconds.pipe(update_downed_state::<T>),
+            process_revive_requests::<T>,
+            update_depletion::<T>,
+        )
+            .chain()
+            .in_set(AttributeSystems::DownedState),
+        (
+            emit_attribute_changes::<T>,
+            update_threshold_markers::<T>,
+            update_watermarks::<T>.before(evaluate_achievements),
+            delta_seconds.pipe(animate_displayed_attributes::<T>),
+            delta_seconds.pipe(record_history::<T>),
+        )
+            .in_set(AttributeSystems::Changes),
+    ));
+}
+
+/// Register an event the same way as `App::add_event`, leaving an already registered event alone.
+fn register_event<E: Event>(world: &mut World) {
+    if !world.contains_resource::<Events<E>>() {
+        EventRegistry::register_event::<E>(world);
+    }
+}
@@ -0,0 +1,191 @@
+//! # Replication
+//!
+//! This module contains the `Replicate` trait and the `ReplicationTracker` component, for sending only the parts of
+//! an attribute that changed since the last update.
+//!
+//! Most network updates change an attribute's current value and nothing else, so sending the full `WireFormat`
+//! encoding every time wastes two thirds of the bytes. A delta starts with a single mask byte saying which fields
+//! follow, then each changed field in the same fixed-width little-endian encoding as `WireFormat`:
+//!
+//! | Bit | Field     |
+//! |-----|-----------|
+//! | 0   | `min`     |
+//! | 1   | `max`     |
+//! | 2   | `current` |
+//!
+//! The sender keeps a `ReplicationTracker` per attribute, which remembers what was last sent. The receiver applies
+//! each delta with `Replicate::apply_delta`, which validates the result like `WireFormat` does.
+
+use bevy_ecs::component::Component;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    wire::read_array, AttributeError, FixedDecimalAttribute, FloatAttribute, IntegerAttribute,
+};
+
+/// The mask bit for `min`.
+const MIN_CHANGED: u8 = 0b001;
+/// The mask bit for `max`.
+const MAX_CHANGED: u8 = 0b010;
+/// The mask bit for `current`.
+const CURRENT_CHANGED: u8 = 0b100;
+
+/// A type that can be sent as a delta against a previously sent value.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{replication::Replicate, IntegerAttribute};
+///
+/// let before = IntegerAttribute::new(100);
+/// let after = before - 30;
+///
+/// let mut delta = Vec::new();
+/// after.write_delta(Some(&before), &mut delta);
+/// // The mask byte and the new current value.
+/// assert_eq!(delta.len(), 5);
+///
+/// let mut received = before;
+/// received.apply_delta(&delta).expect("Failed to apply");
+/// assert_eq!(received, 70);
+/// ```
+pub trait Replicate: Sized {
+    /// Append a delta from `baseline` to `self`. With no baseline, every field is written.
+    ///
+    /// A delta for an unchanged value is a single zero byte.
+    fn write_delta(&self, baseline: Option<&Self>, buffer: &mut Vec<u8>);
+
+    /// Apply the delta at the start of `bytes`, returning the number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, changing nothing, if `bytes` is too short, the mask has unknown bits, or the result would be
+    /// an invalid value.
+    fn apply_delta(&mut self, bytes: &[u8]) -> Result<usize, AttributeError>;
+}
+
+/// Implement `Replicate` for an attribute with `min`, `max`, and `current` fields of the given type.
+macro_rules! replicate_min_max_current {
+    ($ty:ty, $field:ty, $bits:ident) => {
+        fn write_delta(&self, baseline: Option<&Self>, buffer: &mut Vec<u8>) {
+            let changed = |field: fn(&$ty) -> $field| {
+                baseline.map_or(true, |baseline| {
+                    field(baseline).$bits() != field(self).$bits()
+                })
+            };
+            let mask_index = buffer.len();
+            let mut mask = 0;
+            buffer.push(mask);
+            if changed(|attribute| attribute.min) {
+                mask |= MIN_CHANGED;
+                buffer.extend_from_slice(&self.min.to_le_bytes());
+            }
+            if changed(|attribute| attribute.max) {
+                mask |= MAX_CHANGED;
+                buffer.extend_from_slice(&self.max.to_le_bytes());
+            }
+            if changed(|attribute| attribute.current) {
+                mask |= CURRENT_CHANGED;
+                buffer.extend_from_slice(&self.current.to_le_bytes());
+            }
+            buffer[mask_index] = mask;
+        }
+
+        fn apply_delta(&mut self, bytes: &[u8]) -> Result<usize, AttributeError> {
+            const WIDTH: usize = std::mem::size_of::<$field>();
+
+            let [mask] = read_array(bytes, 0)?;
+            if mask & !(MIN_CHANGED | MAX_CHANGED | CURRENT_CHANGED) != 0 {
+                return Err(AttributeError::ConversionError(format!(
+                    "Unknown delta mask {mask:#010b}."
+                )));
+            }
+
+            let mut offset = 1;
+            let mut read = |flag: u8, value: $field| -> Result<$field, AttributeError> {
+                if mask & flag == 0 {
+                    return Ok(value);
+                }
+                let value = <$field>::from_le_bytes(read_array(bytes, offset)?);
+                offset += WIDTH;
+                Ok(value)
+            };
+            let min = read(MIN_CHANGED, self.min)?;
+            let max = read(MAX_CHANGED, self.max)?;
+            let current = read(CURRENT_CHANGED, self.current)?;
+
+            *self = Self::new_as_defined(min, max, current)?;
+            Ok(offset)
+        }
+    };
+}
+
+impl Replicate for IntegerAttribute {
+    replicate_min_max_current!(IntegerAttribute, i32, to_le_bytes);
+}
+
+impl<const DECIMALS: u32> Replicate for FixedDecimalAttribute<DECIMALS> {
+    replicate_min_max_current!(FixedDecimalAttribute<DECIMALS>, i64, to_le_bytes);
+}
+
+impl Replicate for FloatAttribute {
+    // Floats are compared by their bits, so any change at all is sent.
+    replicate_min_max_current!(FloatAttribute, f32, to_bits);
+}
+
+/// Remembers the last value sent for an attribute, so that only changes are sent.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{replication::ReplicationTracker, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut tracker = ReplicationTracker::default();
+///
+/// // The first delta is the full value.
+/// assert_eq!(tracker.delta(&health).map(|delta| delta.len()), Some(13));
+/// // Nothing changed, so nothing to send.
+/// assert_eq!(tracker.delta(&health), None);
+///
+/// health -= 5;
+/// assert_eq!(tracker.delta(&health).map(|delta| delta.len()), Some(5));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Component)]
+pub struct ReplicationTracker<T: Replicate + Copy + Send + Sync + 'static> {
+    /// The value last sent, or `None` if nothing has been sent yet.
+    last_sent: Option<T>,
+}
+
+impl<T: Replicate + Copy + Send + Sync + 'static> ReplicationTracker<T> {
+    /// Create a tracker that hasn't sent anything yet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { last_sent: None }
+    }
+
+    /// The value last sent, or `None` if nothing has been sent yet.
+    #[must_use]
+    pub const fn last_sent(&self) -> Option<&T> {
+        self.last_sent.as_ref()
+    }
+
+    /// Build the delta from the last value sent to `value`, and remember `value` as sent.
+    ///
+    /// Returns `None`, remembering nothing, if `value` hasn't changed since the last send.
+    pub fn delta(&mut self, value: &T) -> Option<Vec<u8>> {
+        let mut buffer = Vec::new();
+        value.write_delta(self.last_sent.as_ref(), &mut buffer);
+        if buffer == [0] {
+            return None;
+        }
+        self.last_sent = Some(*value);
+        Some(buffer)
+    }
+
+    /// Forget what was sent, so the next delta is the full value. Use this when a new client connects or a packet
+    /// may have been lost.
+    pub const fn reset(&mut self) {
+        self.last_sent = None;
+    }
+}
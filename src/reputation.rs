@@ -0,0 +1,317 @@
+//! # Reputation
+//!
+//! This module contains the implementation of the `Reputation` component, its tiers, and its tier-change events.
+//!
+//! A `Reputation` tracks a standing with any number of factions. Each standing is an `IntegerAttribute`, so it is
+//! clamped to the configured range, and is grouped into a `ReputationTier` using configurable thresholds. Standings
+//! can optionally decay towards a resting value over time, so that grudges and favors fade.
+//!
+//! Whenever a standing crosses into a different tier, the change is recorded. The `emit_reputation_changes` system
+//! drains those records into `ReputationTierChanged` events.
+
+use std::collections::BTreeMap;
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::IntegerAttribute;
+
+/// How a faction regards the owner of a `Reputation`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReputationTier {
+    /// The faction is hostile.
+    Hostile,
+    /// The faction is indifferent.
+    Neutral,
+    /// The faction is friendly.
+    Friendly,
+    /// The faction holds the owner in the highest regard.
+    Exalted,
+}
+
+/// The lowest standing needed for each tier above `Hostile`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReputationThresholds {
+    /// The lowest standing that is `Neutral`. Anything below is `Hostile`.
+    pub neutral: i32,
+    /// The lowest standing that is `Friendly`.
+    pub friendly: i32,
+    /// The lowest standing that is `Exalted`.
+    pub exalted: i32,
+}
+
+impl Default for ReputationThresholds {
+    /// Standings of -500 and below are hostile, 500 and above are friendly, and 2500 and above are exalted.
+    fn default() -> Self {
+        Self {
+            neutral: -499,
+            friendly: 500,
+            exalted: 2500,
+        }
+    }
+}
+
+impl ReputationThresholds {
+    /// The tier a standing falls into.
+    #[must_use]
+    pub const fn tier(&self, standing: i32) -> ReputationTier {
+        if standing >= self.exalted {
+            ReputationTier::Exalted
+        } else if standing >= self.friendly {
+            ReputationTier::Friendly
+        } else if standing >= self.neutral {
+            ReputationTier::Neutral
+        } else {
+            ReputationTier::Hostile
+        }
+    }
+}
+
+/// Decay of every standing towards a resting value.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReputationDecay {
+    /// The standing that decay moves towards.
+    pub resting: i32,
+    /// How many points per second a standing moves towards `resting`.
+    pub rate_per_second: f32,
+}
+
+/// A record of a standing crossing into a different tier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TierChange {
+    /// The faction whose standing changed.
+    pub faction: String,
+    /// The tier before the change.
+    pub old: ReputationTier,
+    /// The tier after the change.
+    pub new: ReputationTier,
+}
+
+/// An event sent for every `TierChange` recorded by a `Reputation`.
+#[derive(Event, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReputationTierChanged {
+    /// The entity that owns the reputation.
+    pub entity: Entity,
+    /// The change.
+    pub change: TierChange,
+}
+
+/// Standings with any number of factions.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::reputation::{Reputation, ReputationTier};
+///
+/// let mut reputation = Reputation::default();
+/// assert_eq!(reputation.tier("merchants"), ReputationTier::Neutral);
+///
+/// reputation.change("merchants", 600);
+/// assert_eq!(reputation.tier("merchants"), ReputationTier::Friendly);
+///
+/// let changes = reputation.drain_tier_changes();
+/// assert_eq!(changes[0].old, ReputationTier::Neutral);
+/// assert_eq!(changes[0].new, ReputationTier::Friendly);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Reputation {
+    /// The attribute a faction starts with the first time it is seen. This sets the range of every standing.
+    pub initial: IntegerAttribute,
+    /// The thresholds for each tier.
+    pub thresholds: ReputationThresholds,
+    /// Optional decay of standings over time.
+    pub decay: Option<ReputationDecay>,
+    /// The standing with each faction, by faction id.
+    standings: BTreeMap<String, IntegerAttribute>,
+    /// Fractional decay carried over from previous updates.
+    decay_carry: f32,
+    /// Tier changes recorded since they were last drained.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pending: Vec<TierChange>,
+}
+
+impl Default for Reputation {
+    /// Standings range from -3000 to 3000 and start at 0, with the default thresholds and no decay.
+    fn default() -> Self {
+        Self::new(
+            IntegerAttribute {
+                min: -3000,
+                max: 3000,
+                current: 0,
+            },
+            ReputationThresholds::default(),
+        )
+    }
+}
+
+impl Reputation {
+    /// Create a new reputation where every faction starts at `initial`, with the given tier thresholds.
+    #[must_use]
+    pub const fn new(initial: IntegerAttribute, thresholds: ReputationThresholds) -> Self {
+        Self {
+            initial,
+            thresholds,
+            decay: None,
+            standings: BTreeMap::new(),
+            decay_carry: 0.0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Set decay towards `resting` at `rate_per_second`.
+    #[must_use]
+    pub const fn with_decay(mut self, resting: i32, rate_per_second: f32) -> Self {
+        self.decay = Some(ReputationDecay {
+            resting,
+            rate_per_second,
+        });
+        self
+    }
+
+    /// The standing with a faction. Factions that haven't been seen have the initial standing.
+    #[must_use]
+    pub fn standing(&self, faction: &str) -> i32 {
+        self.standings
+            .get(faction)
+            .unwrap_or(&self.initial)
+            .current_value()
+    }
+
+    /// The tier of the standing with a faction.
+    #[must_use]
+    pub fn tier(&self, faction: &str) -> ReputationTier {
+        self.thresholds.tier(self.standing(faction))
+    }
+
+    /// Iterate over the factions that have been seen and their standings, in faction id order.
+    pub fn standings(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.standings
+            .iter()
+            .map(|(faction, standing)| (faction.as_str(), standing.current_value()))
+    }
+
+    /// Change the standing with a faction by `amount`, clamped to the standing's range.
+    ///
+    /// Returns the tier change, if the standing crossed into a different tier.
+    pub fn change(&mut self, faction: &str, amount: i32) -> Option<TierChange> {
+        let standing = self.standing(faction).saturating_add(amount);
+        self.set_standing(faction, standing)
+    }
+
+    /// Set the standing with a faction, clamped to the standing's range.
+    ///
+    /// Returns the tier change, if the standing crossed into a different tier.
+    pub fn set_standing(&mut self, faction: &str, standing: i32) -> Option<TierChange> {
+        let old = self.tier(faction);
+        let initial = self.initial;
+        self.standings
+            .entry(faction.to_string())
+            .or_insert(initial)
+            .set_value(standing);
+        self.record(faction, old)
+    }
+
+    /// Advance decay by `delta_seconds`, moving every standing towards the resting value.
+    ///
+    /// Returns the tier changes caused by the decay. They are also recorded for `drain_tier_changes`.
+    pub fn decay(&mut self, delta_seconds: f32) -> Vec<TierChange> {
+        self.simulate_elapsed(f64::from(delta_seconds))
+    }
+
+    /// Fast-forward decay by `seconds`, such as the time a game was closed, in a single step.
+    ///
+    /// This is `decay` computed in `f64`. Negative or non-finite durations are ignored. Returns the tier changes
+    /// caused by the decay, which are also recorded for `drain_tier_changes`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn simulate_elapsed(&mut self, seconds: f64) -> Vec<TierChange> {
+        let Some(decay) = self.decay else {
+            return Vec::new();
+        };
+        if !seconds.is_finite() || seconds < 0.0 {
+            return Vec::new();
+        }
+
+        let amount =
+            f64::from(decay.rate_per_second.abs()).mul_add(seconds, f64::from(self.decay_carry));
+        let whole = amount.trunc();
+        self.decay_carry = (amount - whole) as f32;
+        // Float to integer casts saturate, so a huge duration is handled.
+        let points = whole as i32;
+        if points <= 0 {
+            return Vec::new();
+        }
+
+        let factions: Vec<String> = self.standings.keys().cloned().collect();
+        factions
+            .iter()
+            .filter_map(|faction| {
+                let standing = self.standing(faction);
+                let decayed = if standing > decay.resting {
+                    standing.saturating_sub(points).max(decay.resting)
+                } else {
+                    standing.saturating_add(points).min(decay.resting)
+                };
+                self.set_standing(faction, decayed)
+            })
+            .collect()
+    }
+
+    /// Take the tier changes recorded since the last call, oldest first.
+    pub fn drain_tier_changes(&mut self) -> Vec<TierChange> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Record a tier change if the faction's tier is no longer `old`.
+    fn record(&mut self, faction: &str, old: ReputationTier) -> Option<TierChange> {
+        let new = self.tier(faction);
+        if new == old {
+            return None;
+        }
+
+        let change = TierChange {
+            faction: faction.to_string(),
+            old,
+            new,
+        };
+        self.pending.push(change.clone());
+        Some(change)
+    }
+}
+
+/// Advance decay of every `Reputation` by the piped-in delta time (in seconds).
+pub fn decay_reputation(In(delta_seconds): In<f32>, mut query: Query<&mut Reputation>) {
+    for mut reputation in &mut query {
+        if reputation.decay.is_some() {
+            reputation.decay(delta_seconds);
+        }
+    }
+}
+
+/// Drain the recorded tier changes of every `Reputation`, sending a `ReputationTierChanged` event for each.
+pub fn emit_reputation_changes(
+    mut query: Query<(Entity, &mut Reputation)>,
+    mut events: EventWriter<ReputationTierChanged>,
+) {
+    for (entity, mut reputation) in &mut query {
+        if reputation.pending.is_empty() {
+            continue;
+        }
+        events.send_batch(
+            reputation
+                .drain_tier_changes()
+                .into_iter()
+                .map(|change| ReputationTierChanged { entity, change }),
+        );
+    }
+}
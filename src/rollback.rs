@@ -0,0 +1,233 @@
+//! # Rollback
+//!
+//! This module contains the `Snapshot` trait and the `RollbackBuffer` component, for client-side prediction and
+//! rollback netcode.
+//!
+//! A client predicting ahead of the server records the state of each attribute every frame. When the server's
+//! authoritative state for an earlier frame arrives and disagrees, the client restores the attribute to that frame
+//! and re-simulates from there. `Snapshot` captures and restores the full state of a value (including fields that
+//! `PartialEq` ignores, such as an attribute's `min` and `max`), and `RollbackBuffer` keeps the last few frames of
+//! snapshots in a ring.
+
+use std::collections::VecDeque;
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Cooldown, FixedDecimalAttribute, FloatAttribute, GaugeAttribute, Health, IntegerAttribute,
+    Mana, ShieldedAttribute, Stamina,
+};
+
+/// A value whose full state can be captured and later restored.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{rollback::Snapshot, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let saved = health.snapshot();
+///
+/// health -= 40;
+/// health.set_max(200).expect("Failed to set max");
+///
+/// health.restore(&saved);
+/// assert_eq!(health, 100);
+/// assert_eq!(health.max, 100);
+/// ```
+pub trait Snapshot {
+    /// The captured state.
+    type State: Clone;
+
+    /// Capture the current state.
+    fn snapshot(&self) -> Self::State;
+
+    /// Restore a previously captured state.
+    fn restore(&mut self, state: &Self::State);
+}
+
+/// Implement `Snapshot` for types whose whole state is a copy of the value.
+macro_rules! snapshot_by_copy {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Snapshot for $ty {
+                type State = Self;
+
+                fn snapshot(&self) -> Self::State {
+                    *self
+                }
+
+                fn restore(&mut self, state: &Self::State) {
+                    *self = *state;
+                }
+            }
+        )*
+    };
+}
+
+snapshot_by_copy!(
+    IntegerAttribute,
+    FloatAttribute,
+    ShieldedAttribute,
+    GaugeAttribute,
+    Cooldown,
+    Health,
+    Mana,
+    Stamina,
+);
+
+impl<const DECIMALS: u32> Snapshot for FixedDecimalAttribute<DECIMALS> {
+    type State = Self;
+
+    fn snapshot(&self) -> Self::State {
+        *self
+    }
+
+    fn restore(&mut self, state: &Self::State) {
+        *self = *state;
+    }
+}
+
+/// The snapshots of a value for the most recent frames, oldest first.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{rollback::RollbackBuffer, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut buffer = RollbackBuffer::<IntegerAttribute>::new(8);
+///
+/// for frame in 0..5 {
+///     buffer.record(frame, &health);
+///     health -= 10;
+/// }
+///
+/// // The server says frame 2 was right; rewind to it.
+/// assert!(buffer.rollback(2, &mut health));
+/// assert_eq!(health, 80);
+/// assert_eq!(buffer.latest_frame(), Some(2));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Component)]
+#[serde(bound(
+    serialize = "T::State: Serialize",
+    deserialize = "T::State: Deserialize<'de>"
+))]
+pub struct RollbackBuffer<T: Snapshot + Send + Sync + 'static>
+where
+    T::State: Send + Sync,
+{
+    /// The most frames kept.
+    capacity: usize,
+    /// The recorded frames and their snapshots, oldest first. Frame numbers are strictly increasing.
+    frames: VecDeque<(u64, T::State)>,
+}
+
+impl<T: Snapshot + Send + Sync + 'static> RollbackBuffer<T>
+where
+    T::State: Send + Sync,
+{
+    /// Create an empty buffer that keeps at most `capacity` frames. A capacity of zero is treated as one.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            frames: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// The most frames kept.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of frames recorded.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Check if no frames are recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The oldest frame that can be restored.
+    #[must_use]
+    pub fn oldest_frame(&self) -> Option<u64> {
+        self.frames.front().map(|(frame, _)| *frame)
+    }
+
+    /// The newest frame recorded.
+    #[must_use]
+    pub fn latest_frame(&self) -> Option<u64> {
+        self.frames.back().map(|(frame, _)| *frame)
+    }
+
+    /// Record the state of `value` for `frame`, dropping the oldest frame if the buffer is full.
+    ///
+    /// Recording a frame that isn't newer than the latest one replaces that frame and discards everything after it,
+    /// as happens when re-simulating after a rollback.
+    pub fn record(&mut self, frame: u64, value: &T) {
+        self.discard_after(frame);
+        if self.latest_frame() == Some(frame) {
+            self.frames.pop_back();
+        }
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back((frame, value.snapshot()));
+    }
+
+    /// The snapshot recorded for `frame`, if it is still in the buffer.
+    #[must_use]
+    pub fn get(&self, frame: u64) -> Option<&T::State> {
+        self.frames
+            .binary_search_by_key(&frame, |(recorded, _)| *recorded)
+            .ok()
+            .map(|index| &self.frames[index].1)
+    }
+
+    /// Restore `value` to its state at `frame` and discard every later frame.
+    ///
+    /// Returns `false`, changing nothing, if `frame` is not in the buffer.
+    pub fn rollback(&mut self, frame: u64, value: &mut T) -> bool {
+        let Some(state) = self.get(frame) else {
+            return false;
+        };
+        value.restore(state);
+        self.discard_after(frame);
+        true
+    }
+
+    /// Discard every recorded frame.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Discard every frame after `frame`.
+    fn discard_after(&mut self, frame: u64) {
+        while self.latest_frame().is_some_and(|latest| latest > frame) {
+            self.frames.pop_back();
+        }
+    }
+}
+
+/// Record the state of every `T` with a `RollbackBuffer<T>` for the piped-in frame number.
+pub fn record_rollback<T: Snapshot + Component>(
+    In(frame): In<u64>,
+    mut query: Query<(&T, &mut RollbackBuffer<T>)>,
+) where
+    T::State: Send + Sync,
+{
+    for (value, mut buffer) in &mut query {
+        buffer.record(frame, value);
+    }
+}
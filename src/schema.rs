@@ -0,0 +1,89 @@
+//! # JSON Schema
+//!
+//! This module generates JSON Schema for the library's serializable types, so that external tooling (editors,
+//! validators) can check content files against them.
+//!
+//! It is only available with the `schema` feature. Every serializable type also implements `schemars::JsonSchema`
+//! when the feature is enabled, so individual schemas can be generated with `schemars::schema_for!` as well.
+
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{RootSchema, SchemaObject},
+};
+
+use crate::{
+    accumulator::{Accumulator, KahanAccumulator},
+    achievements::Achievements,
+    curves::ScalingCurve,
+    editor_hints::EditorHint,
+    experience::{ExponentialCurve, LinearCurve, TableCurve},
+    transfer::TransferPolicy,
+    ui_edit::{AttributeField, EditPolicy},
+    AttributeHistory, BoundedAttribute, Cooldown, DamageBreakdown, Depletable, DisplayedAttribute,
+    DownedState, FixedDecimalAttribute, FloatAttribute, GaugeAttribute, Health, IntegerAttribute,
+    LayeredPools, Level, Mana, OperationQueue, OverflowPolicy, Percent, PeriodicReset,
+    Regeneration, Reputation, ShieldedAttribute, Stamina, Wallet, Watermarks,
+};
+
+/// Generate a single root schema whose `definitions` cover every serializable type in the library.
+///
+/// Generic types are included with their most common instantiation: `LayeredPools` uses `String` damage kinds, and
+/// `FixedDecimalAttribute` uses its default of 3 decimals.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::schema::json_schema_for_all;
+///
+/// let schema = json_schema_for_all();
+/// assert!(schema.definitions.contains_key("IntegerAttribute"));
+/// ```
+#[must_use]
+pub fn json_schema_for_all() -> RootSchema {
+    let mut generator = SchemaGenerator::new(SchemaSettings::draft07());
+
+    generator.subschema_for::<IntegerAttribute>();
+    generator.subschema_for::<FixedDecimalAttribute>();
+    generator.subschema_for::<FloatAttribute>();
+    generator.subschema_for::<LayeredPools<String>>();
+    generator.subschema_for::<DamageBreakdown>();
+    generator.subschema_for::<DownedState>();
+    generator.subschema_for::<Regeneration>();
+    generator.subschema_for::<Level>();
+    generator.subschema_for::<LinearCurve>();
+    generator.subschema_for::<ExponentialCurve>();
+    generator.subschema_for::<TableCurve>();
+    generator.subschema_for::<AttributeHistory>();
+    generator.subschema_for::<Wallet>();
+    generator.subschema_for::<Reputation>();
+    generator.subschema_for::<ShieldedAttribute>();
+    generator.subschema_for::<OverflowPolicy>();
+    generator.subschema_for::<BoundedAttribute>();
+    generator.subschema_for::<Percent>();
+    generator.subschema_for::<Cooldown>();
+    generator.subschema_for::<GaugeAttribute>();
+    generator.subschema_for::<Health>();
+    generator.subschema_for::<Mana>();
+    generator.subschema_for::<Stamina>();
+    generator.subschema_for::<Accumulator>();
+    generator.subschema_for::<KahanAccumulator>();
+    generator.subschema_for::<OperationQueue>();
+    generator.subschema_for::<ScalingCurve>();
+    generator.subschema_for::<DisplayedAttribute>();
+    generator.subschema_for::<TransferPolicy>();
+    generator.subschema_for::<Watermarks>();
+    generator.subschema_for::<Achievements>();
+    generator.subschema_for::<PeriodicReset>();
+    generator.subschema_for::<AttributeField>();
+    generator.subschema_for::<EditPolicy>();
+    generator.subschema_for::<EditorHint>();
+    generator.subschema_for::<Depletable>();
+    #[cfg(feature = "dice")]
+    generator.subschema_for::<crate::dice::Roll>();
+
+    RootSchema {
+        meta_schema: generator.settings().meta_schema.clone(),
+        schema: SchemaObject::default(),
+        definitions: generator.take_definitions(),
+    }
+}
@@ -0,0 +1,214 @@
+//! # Shielded Attribute
+//!
+//! This module contains the implementation of the `ShieldedAttribute` component and the system that decays its shield.
+//!
+//! A `ShieldedAttribute` wraps an `IntegerAttribute` with a shield that soaks incoming damage before the attribute's
+//! current value changes. The shield has its own maximum and can decay over time, which covers barrier, ward, and
+//! overheal mechanics. Healing past the attribute's maximum can optionally be converted into shield with `overheal`.
+
+use bevy_ecs::{
+    component::Component,
+    system::{In, Query},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::IntegerAttribute;
+
+/// How incoming damage was split between the shield and the underlying attribute.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShieldedDamage {
+    /// The damage soaked by the shield.
+    pub absorbed: i32,
+    /// The damage that reached the attribute, after clamping.
+    pub applied: i32,
+}
+
+/// An attribute protected by a shield that absorbs damage first.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{IntegerAttribute, ShieldedAttribute};
+///
+/// let mut health = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+/// health.add_shield(30);
+///
+/// let damage = health.damage(45);
+/// assert_eq!(damage.absorbed, 30);
+/// assert_eq!(damage.applied, 15);
+/// assert_eq!(health.current_value(), 85);
+/// assert_eq!(health.shield_value(), 0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShieldedAttribute {
+    /// The attribute being protected.
+    pub attribute: IntegerAttribute,
+    /// The shield. Its minimum is always 0 and its maximum is the most shield that can be held.
+    shield: IntegerAttribute,
+    /// How many shield points are lost per second. Zero means the shield never decays.
+    pub decay_per_second: f32,
+    /// Fractional decay carried over from previous updates.
+    carry: f32,
+}
+
+impl ShieldedAttribute {
+    /// Create a new shielded attribute with an empty shield that can hold up to `shield_max` points.
+    ///
+    /// Negative maximums are treated as 0.
+    #[must_use]
+    pub const fn new(attribute: IntegerAttribute, shield_max: i32) -> Self {
+        let shield_max = if shield_max < 0 { 0 } else { shield_max };
+        Self {
+            attribute,
+            shield: IntegerAttribute {
+                max: shield_max,
+                min: 0,
+                current: 0,
+            },
+            decay_per_second: 0.0,
+            carry: 0.0,
+        }
+    }
+
+    /// Set how many shield points are lost per second.
+    #[must_use]
+    pub const fn with_decay(mut self, decay_per_second: f32) -> Self {
+        self.decay_per_second = decay_per_second;
+        self
+    }
+
+    /// The current value of the underlying attribute.
+    #[must_use]
+    pub const fn current_value(&self) -> i32 {
+        self.attribute.current_value()
+    }
+
+    /// The current shield.
+    #[must_use]
+    pub const fn shield_value(&self) -> i32 {
+        self.shield.current
+    }
+
+    /// The most shield that can be held.
+    #[must_use]
+    pub const fn shield_max(&self) -> i32 {
+        self.shield.max
+    }
+
+    /// The current value plus the shield, such as the health shown on a health bar with its barrier.
+    ///
+    /// This is on the same scale as the current value. The damage it takes to reach the attribute's minimum is this
+    /// minus `attribute.min`.
+    #[must_use]
+    pub const fn effective_value(&self) -> i32 {
+        self.current_value().saturating_add(self.shield.current)
+    }
+
+    /// Set the most shield that can be held, clamping the current shield to it. Negative maximums are treated as 0.
+    pub fn set_shield_max(&mut self, shield_max: i32) {
+        self.shield.max = shield_max.max(0);
+        self.shield.set_value(self.shield.current);
+    }
+
+    /// Add to the shield, up to its maximum. Negative amounts are ignored.
+    ///
+    /// Returns the amount the shield actually grew by.
+    pub fn add_shield(&mut self, amount: i32) -> i32 {
+        let before = self.shield.current;
+        self.shield += amount.max(0);
+        self.shield.current - before
+    }
+
+    /// Remove the whole shield.
+    pub const fn clear_shield(&mut self) {
+        self.shield.current = 0;
+    }
+
+    /// Apply damage, soaking as much as possible with the shield before reducing the attribute. Negative damage is
+    /// ignored; use `heal` instead.
+    pub fn damage(&mut self, amount: i32) -> ShieldedDamage {
+        let amount = amount.max(0);
+        let absorbed = amount.min(self.shield.current);
+        self.shield -= absorbed;
+
+        let before = self.attribute.current_value();
+        self.attribute -= amount - absorbed;
+        ShieldedDamage {
+            absorbed,
+            applied: before.saturating_sub(self.attribute.current_value()),
+        }
+    }
+
+    /// Heal the underlying attribute, up to its maximum. Negative amounts are ignored.
+    ///
+    /// Returns the amount actually healed.
+    pub fn heal(&mut self, amount: i32) -> i32 {
+        let before = self.attribute.current_value();
+        self.attribute += amount.max(0);
+        self.attribute.current_value().saturating_sub(before)
+    }
+
+    /// Heal the underlying attribute, converting any healing past its maximum into shield.
+    ///
+    /// Returns the amount of shield gained.
+    pub fn overheal(&mut self, amount: i32) -> i32 {
+        let healed = self.heal(amount);
+        self.add_shield(amount.max(0) - healed)
+    }
+
+    /// Advance shield decay by `delta_seconds`.
+    ///
+    /// Returns the amount of shield lost.
+    pub fn decay(&mut self, delta_seconds: f32) -> i32 {
+        self.simulate_elapsed(f64::from(delta_seconds))
+    }
+
+    /// Fast-forward shield decay by `seconds`, such as the time a game was closed, in a single step.
+    ///
+    /// This is `decay` computed in `f64`. Negative or non-finite durations are ignored. Returns the amount of shield
+    /// lost.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn simulate_elapsed(&mut self, seconds: f64) -> i32 {
+        if self.decay_per_second <= 0.0 || self.shield.current == 0 {
+            self.carry = 0.0;
+            return 0;
+        }
+        if !seconds.is_finite() || seconds < 0.0 {
+            return 0;
+        }
+
+        let amount = f64::from(self.decay_per_second).mul_add(seconds, f64::from(self.carry));
+        let whole = amount.trunc();
+        self.carry = (amount - whole) as f32;
+
+        let before = self.shield.current;
+        // Float to integer casts saturate, so a huge duration is handled.
+        self.shield -= whole as i32;
+        before - self.shield.current
+    }
+}
+
+impl std::ops::SubAssign<i32> for ShieldedAttribute {
+    /// Apply damage through the shield. See `damage`.
+    fn sub_assign(&mut self, rhs: i32) {
+        self.damage(rhs);
+    }
+}
+
+impl std::ops::AddAssign<i32> for ShieldedAttribute {
+    /// Heal the underlying attribute. See `heal`.
+    fn add_assign(&mut self, rhs: i32) {
+        self.heal(rhs);
+    }
+}
+
+/// Decay the shield of every `ShieldedAttribute` by the piped-in delta time (in seconds).
+pub fn decay_shields(In(delta_seconds): In<f32>, mut query: Query<&mut ShieldedAttribute>) {
+    for mut shielded in &mut query {
+        if shielded.decay_per_second > 0.0 && shielded.shield_value() > 0 {
+            shielded.decay(delta_seconds);
+        }
+    }
+}
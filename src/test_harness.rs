@@ -0,0 +1,108 @@
+//! # Test Harness
+//!
+//! This module contains `TestHarness`, a small wrapper around a `bevy_ecs::World` that runs the library's systems for
+//! a number of fixed-length ticks.
+//!
+//! It is only available with the `test-util` feature, and is meant for integration tests: spawn entities with the
+//! library's components, run some ticks, then assert on the components and on the events that were sent. It runs the
+//! same systems in the same order as the `AttributesPlugin`, but without needing `bevy_app` or a real clock.
+//!
+//! ```rust
+//! use nwest_shared_component_library::{test_harness::TestHarness, IntegerAttribute, Regeneration};
+//!
+//! let mut harness = TestHarness::new(0.5);
+//! let mut health = IntegerAttribute::new(100);
+//! health.set_value(50);
+//! let entity = harness.world_mut().spawn((health, Regeneration::new(4.0, 1.0))).id();
+//!
+//! // Four half-second ticks is two seconds of regeneration.
+//! harness.run_ticks(4);
+//! assert_eq!(harness.get::<IntegerAttribute>(entity).map(|health| health.current), Some(58));
+//! ```
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, Events},
+    schedule::Schedule,
+    system::{Res, Resource},
+    world::World,
+};
+
+use crate::registration::register_attribute_systems;
+
+/// The length of every tick, in seconds, piped into the time-based systems.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct TickDelta(pub f32);
+
+/// A `World` and `Schedule` running the library's systems with a fixed tick length.
+pub struct TestHarness {
+    /// The world the systems run in.
+    world: World,
+    /// The library's systems, in the same order as the `AttributesPlugin`.
+    schedule: Schedule,
+}
+
+impl TestHarness {
+    /// Create a harness whose ticks are `delta_seconds` long, with the library's events registered.
+    #[must_use]
+    pub fn new(delta_seconds: f32) -> Self {
+        let mut world = World::new();
+        world.insert_resource(TickDelta(delta_seconds));
+
+        let mut schedule = Schedule::default();
+        register_attribute_systems(&mut world, &mut schedule, tick_delta);
+
+        Self { world, schedule }
+    }
+
+    /// The world the systems run in.
+    #[must_use]
+    pub const fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// The world the systems run in, mutably, for spawning entities and sending events.
+    pub const fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Get a component of an entity.
+    #[must_use]
+    pub fn get<C: Component>(&self, entity: Entity) -> Option<&C> {
+        self.world.get::<C>(entity)
+    }
+
+    /// Send an event, to be read on the next tick.
+    pub fn send<E: Event>(&mut self, event: E) {
+        self.world.send_event(event);
+    }
+
+    /// Run the systems once.
+    pub fn tick(&mut self) {
+        self.schedule.run(&mut self.world);
+    }
+
+    /// Run the systems `ticks` times.
+    pub fn run_ticks(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    /// Take every event of type `E` sent since the last call, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `E` was never registered. The library's events are registered by `new`; register others with
+    /// `world_mut().init_resource::<Events<E>>()`.
+    pub fn drain_events<E: Event>(&mut self) -> Vec<E> {
+        self.world.resource_mut::<Events<E>>().drain().collect()
+    }
+}
+
+/// Read the tick length, for piping into the time-based systems.
+#[allow(clippy::needless_pass_by_value)]
+fn tick_delta(delta: Res<TickDelta>) -> f32 {
+    delta.0
+}
@@ -0,0 +1,123 @@
+//! # Test Utilities
+//!
+//! This module contains `proptest` strategies and `Arbitrary` implementations for the library's value types.
+//!
+//! It is only available with the `test-util` feature, which is meant for downstream `dev-dependencies`. Every value the
+//! strategies produce is valid: minimums are never above maximums and current values are within range, so property
+//! tests exercise real game states rather than impossible ones.
+//!
+//! ```rust
+//! use nwest_shared_component_library::test_util::integer_attribute;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(attribute in integer_attribute(), amount in 0..1_000_i32)| {
+//!     let result = attribute - amount;
+//!     prop_assert!(result.current >= attribute.min);
+//! });
+//! ```
+
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{FixedDecimalAttribute, FloatAttribute, IntegerAttribute, Percent};
+
+/// Pick a valid `(min, max, current)` triple for an attribute with bounds in `bounds`.
+fn valid_range<T>(bounds: std::ops::RangeInclusive<T>) -> impl Strategy<Value = (T, T, T)>
+where
+    T: Copy + Ord + std::fmt::Debug + proptest::arbitrary::Arbitrary + 'static,
+    std::ops::RangeInclusive<T>: Strategy<Value = T>,
+{
+    (bounds.clone(), bounds)
+        .prop_map(|(a, b)| (a.min(b), a.max(b)))
+        .prop_flat_map(|(min, max)| (proptest::strategy::Just((min, max)), min..=max))
+        .prop_map(|((min, max), current)| (min, max, current))
+}
+
+/// Any valid `IntegerAttribute`, with bounds anywhere in the `i32` range.
+pub fn integer_attribute() -> impl Strategy<Value = IntegerAttribute> {
+    integer_attribute_within(i32::MIN, i32::MAX)
+}
+
+/// A valid `IntegerAttribute` whose minimum and maximum are both between `lowest` and `highest`.
+///
+/// # Panics
+///
+/// Panics if `lowest` is greater than `highest`.
+pub fn integer_attribute_within(
+    lowest: i32,
+    highest: i32,
+) -> impl Strategy<Value = IntegerAttribute> {
+    assert!(lowest <= highest, "lowest must not be greater than highest");
+    valid_range(lowest..=highest).prop_map(|(min, max, current)| IntegerAttribute {
+        max,
+        min,
+        current,
+    })
+}
+
+/// Any valid `FixedDecimalAttribute`, with bounds anywhere in the `i64` range.
+pub fn fixed_decimal_attribute<const DECIMALS: u32>(
+) -> impl Strategy<Value = FixedDecimalAttribute<DECIMALS>> {
+    valid_range(i64::MIN..=i64::MAX).prop_map(|(min, max, current)| FixedDecimalAttribute {
+        max,
+        min,
+        current,
+    })
+}
+
+/// Any valid `FloatAttribute`, with finite bounds between -1,000,000 and 1,000,000.
+pub fn float_attribute() -> impl Strategy<Value = FloatAttribute> {
+    (
+        -1_000_000.0..=1_000_000.0_f32,
+        -1_000_000.0..=1_000_000.0_f32,
+        0.0..=1.0_f32,
+    )
+        .prop_map(|(a, b, fraction)| {
+            let (min, max) = (a.min(b), a.max(b));
+            let current = (max - min).mul_add(fraction, min).clamp(min, max);
+            FloatAttribute { max, min, current }
+        })
+}
+
+/// Any valid `Percent`.
+pub fn percent() -> impl Strategy<Value = Percent> {
+    (0.0..=1.0_f64).prop_map(Percent::clamped)
+}
+
+impl Arbitrary for IntegerAttribute {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        integer_attribute().boxed()
+    }
+}
+
+impl<const DECIMALS: u32> Arbitrary for FixedDecimalAttribute<DECIMALS> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        fixed_decimal_attribute().boxed()
+    }
+}
+
+impl Arbitrary for FloatAttribute {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        float_attribute().boxed()
+    }
+}
+
+impl Arbitrary for Percent {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        percent().boxed()
+    }
+}
@@ -0,0 +1,218 @@
+//! # Stat Transactions
+//!
+//! This module contains `StatTransaction`, which changes several attributes together or not at all.
+//!
+//! Crafting might cost mana, materials, and tool durability at once. Applying those one by one leaves a half-paid
+//! craft behind if the last check fails. A `StatTransaction` stages every change, checks them all against copies of
+//! the attributes, and only writes the results back if every check passed. On failure nothing is changed and the
+//! error names the change that failed.
+
+use crate::{AttributeError, FixedDecimalAttribute, IntegerAttribute};
+
+/// A staged change to one attribute.
+enum StagedChange<'a> {
+    /// Add to an `IntegerAttribute`. Negative amounts subtract.
+    Add(&'a mut IntegerAttribute, i32),
+    /// Subtract from an `IntegerAttribute`.
+    Spend(&'a mut IntegerAttribute, i32),
+    /// Add units to a `FixedDecimalAttribute`. Negative amounts subtract.
+    AddUnits(&'a mut FixedDecimalAttribute, i64),
+    /// Subtract units from a `FixedDecimalAttribute`.
+    SpendUnits(&'a mut FixedDecimalAttribute, i64),
+}
+
+/// The value an attribute will have once a staged change is applied.
+enum Resolved {
+    /// The new value of an `IntegerAttribute`.
+    Integer(IntegerAttribute),
+    /// The new value of a `FixedDecimalAttribute`.
+    FixedDecimal(FixedDecimalAttribute),
+}
+
+impl StagedChange<'_> {
+    /// Apply the change to a copy of the attribute.
+    fn resolve(&self) -> Result<Resolved, AttributeError> {
+        match self {
+            Self::Add(attribute, amount) => {
+                let mut copy = **attribute;
+                copy.try_add(*amount).map(|_| Resolved::Integer(copy))
+            }
+            Self::Spend(attribute, amount) => {
+                let mut copy = **attribute;
+                copy.try_subtract(*amount).map(|_| Resolved::Integer(copy))
+            }
+            Self::AddUnits(attribute, units) => {
+                let mut copy = **attribute;
+                copy.try_add(*units).map(|_| Resolved::FixedDecimal(copy))
+            }
+            Self::SpendUnits(attribute, units) => {
+                let mut copy = **attribute;
+                copy.try_subtract(*units)
+                    .map(|_| Resolved::FixedDecimal(copy))
+            }
+        }
+    }
+
+    /// Write a resolved value back to the attribute.
+    fn store(self, resolved: Resolved) {
+        match (self, resolved) {
+            (Self::Add(attribute, _) | Self::Spend(attribute, _), Resolved::Integer(value)) => {
+                *attribute = value;
+            }
+            (
+                Self::AddUnits(attribute, _) | Self::SpendUnits(attribute, _),
+                Resolved::FixedDecimal(value),
+            ) => *attribute = value,
+            _ => unreachable!("a change always resolves to its own attribute type"),
+        }
+    }
+}
+
+/// A set of attribute changes that are applied atomically.
+///
+/// Every change must keep its attribute within `min` and `max`; no change is clamped.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     transaction::StatTransaction, AttributeError, FixedDecimalAttribute, IntegerAttribute,
+/// };
+///
+/// let mut mana = IntegerAttribute::new(50);
+/// let mut iron = FixedDecimalAttribute::<3>::new(1_500);
+/// let mut durability = IntegerAttribute::new(3);
+///
+/// // Not enough iron, so nothing is spent.
+/// let result = StatTransaction::new()
+///     .spend("mana", &mut mana, 30)
+///     .spend_units("iron", &mut iron, 2_000)
+///     .spend("durability", &mut durability, 1)
+///     .commit();
+/// assert!(matches!(result, Err(AttributeError::TransactionFailed(label, _)) if label == "iron"));
+/// assert_eq!(mana, 50);
+///
+/// StatTransaction::new()
+///     .spend("mana", &mut mana, 30)
+///     .spend_units("iron", &mut iron, 1_000)
+///     .spend("durability", &mut durability, 1)
+///     .commit()
+///     .expect("Should be able to craft");
+/// assert_eq!(mana, 20);
+/// assert_eq!(durability, 2);
+/// ```
+#[derive(Default)]
+pub struct StatTransaction<'a> {
+    /// The staged changes, labeled for error reporting, in the order they were staged.
+    changes: Vec<(String, StagedChange<'a>)>,
+}
+
+impl<'a> StatTransaction<'a> {
+    /// Create an empty transaction.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Stage adding `amount` to an attribute. Negative amounts subtract.
+    #[must_use]
+    pub fn add(
+        mut self,
+        label: impl Into<String>,
+        attribute: &'a mut IntegerAttribute,
+        amount: i32,
+    ) -> Self {
+        self.changes
+            .push((label.into(), StagedChange::Add(attribute, amount)));
+        self
+    }
+
+    /// Stage subtracting `amount` from an attribute.
+    #[must_use]
+    pub fn spend(
+        mut self,
+        label: impl Into<String>,
+        attribute: &'a mut IntegerAttribute,
+        amount: i32,
+    ) -> Self {
+        self.changes
+            .push((label.into(), StagedChange::Spend(attribute, amount)));
+        self
+    }
+
+    /// Stage adding `units` to a fixed decimal attribute. Negative amounts subtract.
+    #[must_use]
+    pub fn add_units(
+        mut self,
+        label: impl Into<String>,
+        attribute: &'a mut FixedDecimalAttribute,
+        units: i64,
+    ) -> Self {
+        self.changes
+            .push((label.into(), StagedChange::AddUnits(attribute, units)));
+        self
+    }
+
+    /// Stage subtracting `units` from a fixed decimal attribute.
+    #[must_use]
+    pub fn spend_units(
+        mut self,
+        label: impl Into<String>,
+        attribute: &'a mut FixedDecimalAttribute,
+        units: i64,
+    ) -> Self {
+        self.changes
+            .push((label.into(), StagedChange::SpendUnits(attribute, units)));
+        self
+    }
+
+    /// The number of staged changes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Check if no changes are staged.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Check every staged change without applying any of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::TransactionFailed` with the label of the first change that would leave its attribute
+    /// out of bounds, wrapping the underlying error.
+    pub fn validate(&self) -> Result<(), AttributeError> {
+        self.resolve_all().map(|_| ())
+    }
+
+    /// Apply every staged change, or none of them if any would leave its attribute out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeError::TransactionFailed` with the label of the first change that failed, wrapping the
+    /// underlying error. No attribute is changed.
+    pub fn commit(self) -> Result<(), AttributeError> {
+        let resolved = self.resolve_all()?;
+        for ((_, change), value) in self.changes.into_iter().zip(resolved) {
+            change.store(value);
+        }
+        Ok(())
+    }
+
+    /// Resolve every staged change against copies of the attributes.
+    fn resolve_all(&self) -> Result<Vec<Resolved>, AttributeError> {
+        self.changes
+            .iter()
+            .map(|(label, change)| {
+                change.resolve().map_err(|error| {
+                    AttributeError::TransactionFailed(label.clone(), Box::new(error))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,327 @@
+//! # Transfers
+//!
+//! This module contains `transfer` and `transfer_units`, which move value from one attribute to another, and
+//! `convert_between` and `convert_units_between`, which do the same at an exchange rate.
+//!
+//! Life drain, mana batteries, and shared pools all take from one attribute and give to another. Doing that with a
+//! subtract and an add makes it easy to give more than was taken, for example by adding the requested amount when the
+//! source only had half of it. These functions compute what can actually leave the source and what can actually
+//! arrive at the destination together, so a transfer never creates value. Depending on the `TransferPolicy` it may
+//! destroy value that the destination has no room for.
+//!
+//! Conversions, such as sacrificing health for mana at 2:1, round down what arrives at the destination. With
+//! `TransferPolicy::Clamp` only the amount that actually converts is taken, so a remainder too small to convert stays
+//! in the source.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{wallet::ExchangeRate, AttributeError, FixedDecimalAttribute, IntegerAttribute};
+
+/// What to do when a transfer can't be completed in full.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TransferPolicy {
+    /// Move as much as the source can give and the destination can hold. Nothing is lost.
+    #[default]
+    Clamp,
+    /// Take as much as the source can give, even if the destination can't hold all of it. The excess is lost, like a
+    /// life drain on a target at full health.
+    Drain,
+    /// Move the full amount or nothing.
+    Exact,
+}
+
+/// How much a transfer took from the source and gave to the destination.
+///
+/// `received` is never more than `taken`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Transferred<T> {
+    /// The amount removed from the source.
+    pub taken: T,
+    /// The amount added to the destination.
+    pub received: T,
+}
+
+/// Move up to `amount` from one attribute to another. Negative amounts are treated as 0.
+///
+/// # Errors
+///
+/// With `TransferPolicy::Exact`, returns `AttributeError::OutOfBounds` for whichever attribute can't take the full
+/// amount, and changes nothing. The other policies never fail.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     transfer::{transfer, TransferPolicy},
+///     IntegerAttribute,
+/// };
+///
+/// let mut target = IntegerAttribute::new(100);
+/// target.set_value(15);
+/// let mut caster = IntegerAttribute::new(100);
+/// caster.set_value(90);
+///
+/// // The target only has 15 to give, and the caster only has room for 10.
+/// let drained = transfer(&mut target, &mut caster, 25, TransferPolicy::Clamp).expect("Clamp never fails");
+/// assert_eq!(drained.taken, 10);
+/// assert_eq!(drained.received, 10);
+/// assert_eq!(target, 5);
+/// assert_eq!(caster, 100);
+/// ```
+pub fn transfer(
+    from: &mut IntegerAttribute,
+    to: &mut IntegerAttribute,
+    amount: i32,
+    policy: TransferPolicy,
+) -> Result<Transferred<i32>, AttributeError> {
+    let (source, destination) = (Bounds::of_integer(from), Bounds::of_integer(to));
+    let moved = plan(source, destination, i64::from(amount), policy)?;
+    // The new values are within each attribute's limits, so they fit in an `i32`. The amounts can span the whole
+    // `i32` range, so they are reported saturated.
+    let narrow = |value: i64| i32::try_from(value).unwrap_or(i32::MAX);
+    from.set_value(narrow(source.current - moved.taken));
+    to.set_value(narrow(destination.current + moved.received));
+    Ok(Transferred {
+        taken: narrow(moved.taken),
+        received: narrow(moved.received),
+    })
+}
+
+/// Move up to `units` from one fixed decimal attribute to another. Negative amounts are treated as 0.
+///
+/// # Errors
+///
+/// With `TransferPolicy::Exact`, returns `AttributeError::OutOfBounds` for whichever attribute can't take the full
+/// amount, and changes nothing. The other policies never fail.
+pub fn transfer_units<const DECIMALS: u32>(
+    from: &mut FixedDecimalAttribute<DECIMALS>,
+    to: &mut FixedDecimalAttribute<DECIMALS>,
+    units: i64,
+    policy: TransferPolicy,
+) -> Result<Transferred<i64>, AttributeError> {
+    let moved = plan(
+        Bounds::of_fixed_decimal(from),
+        Bounds::of_fixed_decimal(to),
+        units,
+        policy,
+    )?;
+    from.set_value(from.current_value() - moved.taken);
+    to.set_value(to.current_value() + moved.received);
+    Ok(moved)
+}
+
+/// Convert up to `amount` of one attribute into another at `rate`. Negative amounts are treated as 0.
+///
+/// `rate` converts from the source to the destination, rounding down. The returned `taken` is in the source's units
+/// and `received` is in the destination's.
+///
+/// # Errors
+///
+/// With `TransferPolicy::Exact`, returns `AttributeError::OutOfBounds` for whichever attribute can't take the full
+/// amount, and changes nothing. The other policies never fail.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     transfer::{convert_between, TransferPolicy},
+///     wallet::ExchangeRate,
+///     IntegerAttribute,
+/// };
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut mana = IntegerAttribute::new(100);
+/// mana.set_value(0);
+///
+/// // Sacrifice health for mana at 2:1. Only 14 of the 15 health converts, so the last point is kept.
+/// let half = ExchangeRate::new(1, 2).expect("Invalid rate");
+/// let converted = convert_between(&mut health, &mut mana, half, 15, TransferPolicy::Clamp).expect("Clamp never fails");
+/// assert_eq!(converted.taken, 14);
+/// assert_eq!(converted.received, 7);
+/// assert_eq!(health, 86);
+/// assert_eq!(mana, 7);
+/// ```
+pub fn convert_between(
+    from: &mut IntegerAttribute,
+    to: &mut IntegerAttribute,
+    rate: ExchangeRate,
+    amount: i32,
+    policy: TransferPolicy,
+) -> Result<Transferred<i32>, AttributeError> {
+    let (source, destination) = (Bounds::of_integer(from), Bounds::of_integer(to));
+    let moved = plan_conversion(source, destination, i64::from(amount), rate, policy)?;
+    // As in `transfer`, the new values fit in an `i32` and the amounts are reported saturated.
+    let narrow = |value: i64| i32::try_from(value).unwrap_or(i32::MAX);
+    from.set_value(narrow(source.current - moved.taken));
+    to.set_value(narrow(destination.current + moved.received));
+    Ok(Transferred {
+        taken: narrow(moved.taken),
+        received: narrow(moved.received),
+    })
+}
+
+/// Convert up to `units` of one fixed decimal attribute into another at `rate`. Negative amounts are treated as 0.
+///
+/// # Errors
+///
+/// With `TransferPolicy::Exact`, returns `AttributeError::OutOfBounds` for whichever attribute can't take the full
+/// amount, and changes nothing. The other policies never fail.
+pub fn convert_units_between<const FROM: u32, const TO: u32>(
+    from: &mut FixedDecimalAttribute<FROM>,
+    to: &mut FixedDecimalAttribute<TO>,
+    rate: ExchangeRate,
+    units: i64,
+    policy: TransferPolicy,
+) -> Result<Transferred<i64>, AttributeError> {
+    let moved = plan_conversion(
+        Bounds::of_fixed_decimal(from),
+        Bounds::of_fixed_decimal(to),
+        units,
+        rate,
+        policy,
+    )?;
+    from.set_value(from.current_value() - moved.taken);
+    to.set_value(to.current_value() + moved.received);
+    Ok(moved)
+}
+
+/// The current value and limits of an attribute, widened so differences can't overflow.
+#[derive(Clone, Copy)]
+struct Bounds {
+    /// The current value.
+    current: i64,
+    /// The minimum value.
+    min: i64,
+    /// The maximum value.
+    max: i64,
+}
+
+impl Bounds {
+    /// The bounds of an integer attribute.
+    fn of_integer(attribute: &IntegerAttribute) -> Self {
+        Self {
+            current: i64::from(attribute.current_value()),
+            min: i64::from(attribute.min),
+            max: i64::from(attribute.max),
+        }
+    }
+
+    /// The bounds of a fixed decimal attribute.
+    const fn of_fixed_decimal<const DECIMALS: u32>(
+        attribute: &FixedDecimalAttribute<DECIMALS>,
+    ) -> Self {
+        Self {
+            current: attribute.current_value(),
+            min: attribute.min,
+            max: attribute.max,
+        }
+    }
+
+    /// How much can be taken before reaching the minimum.
+    const fn available(self) -> i64 {
+        self.current.saturating_sub(self.min)
+    }
+
+    /// How much can be added before reaching the maximum.
+    const fn room(self) -> i64 {
+        self.max.saturating_sub(self.current)
+    }
+}
+
+/// Work out how much to take and give without changing anything.
+fn plan(
+    from: Bounds,
+    to: Bounds,
+    amount: i64,
+    policy: TransferPolicy,
+) -> Result<Transferred<i64>, AttributeError> {
+    let amount = amount.max(0);
+    match policy {
+        TransferPolicy::Clamp => {
+            let moved = amount.min(from.available()).min(to.room());
+            Ok(Transferred {
+                taken: moved,
+                received: moved,
+            })
+        }
+        TransferPolicy::Drain => {
+            let taken = amount.min(from.available());
+            Ok(Transferred {
+                taken,
+                received: taken.min(to.room()),
+            })
+        }
+        TransferPolicy::Exact => {
+            if amount > from.available() {
+                return Err(AttributeError::OutOfBounds(
+                    from.current.saturating_sub(amount),
+                    from.min,
+                    from.max,
+                ));
+            }
+            if amount > to.room() {
+                return Err(AttributeError::OutOfBounds(
+                    to.current.saturating_add(amount),
+                    to.min,
+                    to.max,
+                ));
+            }
+            Ok(Transferred {
+                taken: amount,
+                received: amount,
+            })
+        }
+    }
+}
+
+/// Work out how much to take and give at an exchange rate without changing anything.
+fn plan_conversion(
+    from: Bounds,
+    to: Bounds,
+    amount: i64,
+    rate: ExchangeRate,
+    policy: TransferPolicy,
+) -> Result<Transferred<i64>, AttributeError> {
+    let amount = amount.max(0);
+    match policy {
+        TransferPolicy::Clamp => {
+            // The most that converts to no more than the destination's room, so none of it is lost.
+            let fits = rate.cost(to.room().saturating_add(1)).saturating_sub(1);
+            let received = rate.convert(amount.min(from.available()).min(fits));
+            Ok(Transferred {
+                taken: rate.cost(received),
+                received,
+            })
+        }
+        TransferPolicy::Drain => {
+            let taken = amount.min(from.available());
+            Ok(Transferred {
+                taken,
+                received: rate.convert(taken).min(to.room()),
+            })
+        }
+        TransferPolicy::Exact => {
+            if amount > from.available() {
+                return Err(AttributeError::OutOfBounds(
+                    from.current.saturating_sub(amount),
+                    from.min,
+                    from.max,
+                ));
+            }
+            let received = rate.convert(amount);
+            if received > to.room() {
+                return Err(AttributeError::OutOfBounds(
+                    to.current.saturating_add(received),
+                    to.min,
+                    to.max,
+                ));
+            }
+            Ok(Transferred {
+                taken: amount,
+                received,
+            })
+        }
+    }
+}
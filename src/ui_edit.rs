@@ -0,0 +1,195 @@
+//! # UI Edits
+//!
+//! This module contains the `UiEdit` trait, which applies an edit to one field of an attribute coming from an
+//! inspector or editor widget.
+//!
+//! A widget edits one field at a time, but an attribute must always keep `min <= current <= max`. Rather than make
+//! every editor understand that, `apply_ui_edit` validates the edit and fixes up the other fields according to an
+//! `EditPolicy`, then reports every field that changed so the widget can refresh them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeError, FixedDecimalAttribute, FloatAttribute, IntegerAttribute};
+
+/// A field of an attribute.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AttributeField {
+    /// The minimum.
+    Min,
+    /// The maximum.
+    Max,
+    /// The current value.
+    Current,
+}
+
+/// How an edit that doesn't fit the other fields is handled.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EditPolicy {
+    /// The other fields move to fit the edit: a minimum above the maximum raises the maximum, and a current value
+    /// outside the range widens it.
+    #[default]
+    Adjust,
+    /// The edited value is clamped to what the other fields allow.
+    Clamp,
+    /// The edit fails without any change.
+    Reject,
+}
+
+/// A field that changed because of an edit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldChange<T> {
+    /// The field that changed.
+    pub field: AttributeField,
+    /// The value before the edit.
+    pub old: T,
+    /// The value after the edit.
+    pub new: T,
+}
+
+/// An attribute that can be edited one field at a time.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{
+///     ui_edit::{AttributeField, EditPolicy, FieldChange, UiEdit},
+///     IntegerAttribute,
+/// };
+///
+/// let mut health = IntegerAttribute::new(100);
+///
+/// // Lowering the maximum below the current value pulls the current value down with it.
+/// let changes = health
+///     .apply_ui_edit(AttributeField::Max, 80, EditPolicy::Adjust)
+///     .expect("Failed to edit");
+/// assert_eq!(
+///     changes,
+///     vec![
+///         FieldChange { field: AttributeField::Max, old: 100, new: 80 },
+///         FieldChange { field: AttributeField::Current, old: 100, new: 80 },
+///     ]
+/// );
+///
+/// // A maximum below the minimum can be refused instead.
+/// assert!(health.apply_ui_edit(AttributeField::Max, -5, EditPolicy::Reject).is_err());
+/// ```
+pub trait UiEdit {
+    /// The type of the attribute's fields.
+    type Value;
+
+    /// Set `field` to `value`, fixing up the other fields according to `policy`.
+    ///
+    /// The current value is always clamped to the resulting range. Returns every field that changed, in `Min`, `Max`,
+    /// `Current` order; an edit that changes nothing returns an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the attribute unchanged, if the value is NaN, or if the policy is
+    /// `EditPolicy::Reject` and the value doesn't fit the other fields.
+    fn apply_ui_edit(
+        &mut self,
+        field: AttributeField,
+        value: Self::Value,
+        policy: EditPolicy,
+    ) -> Result<Vec<FieldChange<Self::Value>>, AttributeError>;
+}
+
+/// Implement `UiEdit` for an attribute with public `min`, `max`, and `current` fields of type `$value`.
+macro_rules! ui_edit_min_max_current {
+    ($value:ty) => {
+        type Value = $value;
+
+        // Any change at all is reported, so floats are compared exactly.
+        #[allow(clippy::float_cmp)]
+        fn apply_ui_edit(
+            &mut self,
+            field: AttributeField,
+            value: $value,
+            policy: EditPolicy,
+        ) -> Result<Vec<FieldChange<$value>>, AttributeError> {
+            // Only NaN is unordered with itself.
+            if value.partial_cmp(&value).is_none() {
+                return Err(AttributeError::AttributeError(format!(
+                    "Cannot set {field:?} to {value}."
+                )));
+            }
+
+            let (mut min, mut max, mut current) = (self.min, self.max, self.current);
+            match (field, policy) {
+                (AttributeField::Min, EditPolicy::Reject) if value > max => {
+                    return Err(AttributeError::AttributeError(format!(
+                        "Minimum value greater than maximum value. {value} > {max}"
+                    )));
+                }
+                (AttributeField::Min, EditPolicy::Clamp) if value > max => min = max,
+                (AttributeField::Min, _) => {
+                    min = value;
+                    if max < min {
+                        max = min;
+                    }
+                }
+                (AttributeField::Max, EditPolicy::Reject) if value < min => {
+                    return Err(AttributeError::AttributeError(format!(
+                        "Maximum value less than minimum value. {value} < {min}"
+                    )));
+                }
+                (AttributeField::Max, EditPolicy::Clamp) if value < min => max = min,
+                (AttributeField::Max, _) => {
+                    max = value;
+                    if min > max {
+                        min = max;
+                    }
+                }
+                (AttributeField::Current, EditPolicy::Reject) if value < min || value > max => {
+                    return Err(AttributeError::AttributeError(format!(
+                        "Current value outside of minimum and maximum. {min} <= {value} <= {max}"
+                    )));
+                }
+                (AttributeField::Current, EditPolicy::Adjust) => {
+                    current = value;
+                    if min > current {
+                        min = current;
+                    }
+                    if max < current {
+                        max = current;
+                    }
+                }
+                (AttributeField::Current, _) => current = value,
+            }
+            if current < min {
+                current = min;
+            } else if current > max {
+                current = max;
+            }
+
+            let changes = [
+                (AttributeField::Min, self.min, min),
+                (AttributeField::Max, self.max, max),
+                (AttributeField::Current, self.current, current),
+            ]
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| FieldChange { field, old, new })
+            .collect();
+
+            self.min = min;
+            self.max = max;
+            self.current = current;
+            Ok(changes)
+        }
+    };
+}
+
+impl UiEdit for IntegerAttribute {
+    ui_edit_min_max_current!(i32);
+}
+
+impl<const DECIMALS: u32> UiEdit for FixedDecimalAttribute<DECIMALS> {
+    ui_edit_min_max_current!(i64);
+}
+
+impl UiEdit for FloatAttribute {
+    ui_edit_min_max_current!(f32);
+}
@@ -0,0 +1,177 @@
+//! # Vitals
+//!
+//! This module contains the `Health`, `Mana`, and `Stamina` components and the `VitalsBundle` that spawns them.
+//!
+//! Each vital is a named wrapper around an `IntegerAttribute`. Giving the pools their own types lets systems query
+//! `&Health` or `&mut Mana` directly instead of guessing which raw `IntegerAttribute` on an entity is which. The
+//! wrappers dereference to the attribute, so all of its methods are available, and support `+=` and `-=` directly.
+//!
+//! The library's systems are generic over `AttributeComponent`, and the `AttributesPlugin` runs them for `Health` as
+//! well as for bare `IntegerAttribute`s, so `Regeneration`, `DownedState`, `Depletable`, and the other companion
+//! components on a `VitalsBundle` entity follow its health. Companion components belong to the entity rather than to
+//! one pool, so `Mana` and `Stamina` aren't registered by default; add `regenerate::<Mana>` and the like for entities
+//! whose only pool is mana or stamina.
+
+use bevy_ecs::{bundle::Bundle, component::Component};
+use serde::{Deserialize, Serialize};
+
+use crate::IntegerAttribute;
+
+/// A component that holds an `IntegerAttribute`, so the library's systems can run on it.
+pub trait AttributeComponent: Component {
+    /// The attribute held by the component.
+    fn attribute(&self) -> &IntegerAttribute;
+
+    /// The attribute held by the component, mutably.
+    fn attribute_mut(&mut self) -> &mut IntegerAttribute;
+}
+
+impl AttributeComponent for IntegerAttribute {
+    fn attribute(&self) -> &IntegerAttribute {
+        self
+    }
+
+    fn attribute_mut(&mut self) -> &mut IntegerAttribute {
+        self
+    }
+}
+
+/// Define a named wrapper component around an `IntegerAttribute`.
+macro_rules! vital {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Component)]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        pub struct $name(pub IntegerAttribute);
+
+        impl $name {
+            /// Create a new pool with a maximum of `max`, starting full.
+            #[must_use]
+            pub const fn new(max: i32) -> Self {
+                Self(IntegerAttribute::new(max))
+            }
+        }
+
+        impl From<IntegerAttribute> for $name {
+            fn from(attribute: IntegerAttribute) -> Self {
+                Self(attribute)
+            }
+        }
+
+        impl From<$name> for IntegerAttribute {
+            fn from(vital: $name) -> Self {
+                vital.0
+            }
+        }
+
+        impl AttributeComponent for $name {
+            fn attribute(&self) -> &IntegerAttribute {
+                &self.0
+            }
+
+            fn attribute_mut(&mut self) -> &mut IntegerAttribute {
+                &mut self.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = IntegerAttribute;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl std::ops::AddAssign<i32> for $name {
+            fn add_assign(&mut self, rhs: i32) {
+                self.0 += rhs;
+            }
+        }
+
+        impl std::ops::SubAssign<i32> for $name {
+            fn sub_assign(&mut self, rhs: i32) {
+                self.0 -= rhs;
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+vital!(
+    /// The health pool of a character.
+    Health
+);
+
+vital!(
+    /// The mana pool of a character.
+    Mana
+);
+
+vital!(
+    /// The stamina pool of a character.
+    Stamina
+);
+
+/// The standard pools of a character, so a character can be spawned in one call.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::world::World;
+/// use nwest_shared_component_library::vitals::{Health, Mana, VitalsBundle};
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(VitalsBundle::new(100, 50, 75)).id();
+///
+/// let mut health = world.get_mut::<Health>(entity).expect("Missing health");
+/// *health -= 30;
+/// assert_eq!(health.current_value(), 70);
+///
+/// assert_eq!(world.get::<Mana>(entity).expect("Missing mana").max, 50);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Bundle)]
+pub struct VitalsBundle {
+    /// The health pool.
+    pub health: Health,
+    /// The mana pool.
+    pub mana: Mana,
+    /// The stamina pool.
+    pub stamina: Stamina,
+}
+
+impl VitalsBundle {
+    /// Create the pools with the given maximums, each starting full.
+    #[must_use]
+    pub const fn new(health: i32, mana: i32, stamina: i32) -> Self {
+        Self {
+            health: Health::new(health),
+            mana: Mana::new(mana),
+            stamina: Stamina::new(stamina),
+        }
+    }
+
+    /// Create the pools from existing attributes.
+    #[must_use]
+    pub const fn from_attributes(
+        health: IntegerAttribute,
+        mana: IntegerAttribute,
+        stamina: IntegerAttribute,
+    ) -> Self {
+        Self {
+            health: Health(health),
+            mana: Mana(mana),
+            stamina: Stamina(stamina),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! # Wallet
+//!
+//! This module contains the implementation of the `Wallet` component, its exchange rates, and its transaction events.
+//!
+//! A `Wallet` holds any number of named currencies, each backed by a `FixedDecimalAttribute` so that it has its own
+//! minimum and maximum and never picks up rounding error. All amounts are in the attribute's units (thousandths).
+//!
+//! Every successful deposit, spend, or exchange is recorded as a `Transaction`. The `emit_wallet_transactions`
+//! system drains those records into `WalletTransaction` events, so shops, UI, and audio can react without polling balances.
+
+use std::collections::BTreeMap;
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    system::Query,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeError, FixedDecimalAttribute};
+
+/// A conversion rate between two currencies, expressed as a ratio to keep it exact.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::wallet::ExchangeRate;
+///
+/// // 100 silver for 1 gold.
+/// let silver_to_gold = ExchangeRate::new(1, 100).expect("Invalid rate");
+/// assert_eq!(silver_to_gold.convert(250), 2);
+/// assert_eq!(silver_to_gold.inverse().convert(2), 200);
+/// assert_eq!(silver_to_gold.cost(2), 200);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExchangeRate {
+    /// The amount of the target currency received for `denominator` of the source currency.
+    numerator: i64,
+    /// The amount of the source currency needed to receive `numerator` of the target currency.
+    denominator: i64,
+}
+
+impl ExchangeRate {
+    /// Create a rate where `denominator` of the source currency buys `numerator` of the target currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either side of the rate is not positive.
+    pub fn new(numerator: i64, denominator: i64) -> Result<Self, AttributeError> {
+        if numerator <= 0 || denominator <= 0 {
+            return Err(AttributeError::ConversionError(format!(
+                "Exchange rate {numerator}:{denominator} must be positive."
+            )));
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Convert an amount of the source currency to the target currency, rounding down.
+    ///
+    /// The result saturates at `i64::MAX`.
+    #[must_use]
+    pub fn convert(&self, amount: i64) -> i64 {
+        let converted =
+            i128::from(amount) * i128::from(self.numerator) / i128::from(self.denominator);
+        i64::try_from(converted).unwrap_or(if converted < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// The least amount of the source currency that converts to at least `amount` of the target currency.
+    ///
+    /// The result saturates at `i64::MAX`.
+    #[must_use]
+    pub fn cost(&self, amount: i64) -> i64 {
+        let scaled = i128::from(amount) * i128::from(self.denominator);
+        let numerator = i128::from(self.numerator);
+        // Division rounds towards zero, so only positive remainders need rounding up.
+        let cost = scaled / numerator + i128::from(scaled > 0 && scaled % numerator != 0);
+        i64::try_from(cost).unwrap_or(if cost < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// The rate converting in the opposite direction.
+    #[must_use]
+    pub const fn inverse(&self) -> Self {
+        Self {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+}
+
+/// A record of a successful change to one currency in a wallet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Transaction {
+    /// The currency that changed.
+    pub currency: String,
+    /// The amount the balance changed by. Deposits are positive and spends are negative.
+    pub amount: i64,
+    /// The balance after the change.
+    pub balance: i64,
+}
+
+/// An event sent for every `Transaction` recorded by a `Wallet`.
+#[derive(Event, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WalletTransaction {
+    /// The entity that owns the wallet.
+    pub entity: Entity,
+    /// The transaction.
+    pub transaction: Transaction,
+}
+
+/// A collection of named currencies, each with its own limits.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{FixedDecimalAttribute, Wallet};
+///
+/// let mut wallet = Wallet::new().with_currency("gold", FixedDecimalAttribute::new(1_000_000));
+///
+/// wallet.try_spend("gold", 250_000).expect("Should be able to afford it");
+/// assert_eq!(wallet.balance("gold"), Some(750_000));
+///
+/// // Spending more than is available fails and leaves the balance alone.
+/// assert!(wallet.try_spend("gold", 1_000_000).is_err());
+/// assert_eq!(wallet.balance("gold"), Some(750_000));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Wallet {
+    /// The currencies held, by name.
+    currencies: BTreeMap<String, FixedDecimalAttribute>,
+    /// Transactions recorded since they were last drained.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pending: Vec<Transaction>,
+}
+
+impl Wallet {
+    /// Create an empty wallet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a currency, replacing any existing currency with the same name.
+    #[must_use]
+    pub fn with_currency(mut self, name: impl Into<String>, limits: FixedDecimalAttribute) -> Self {
+        self.add_currency(name, limits);
+        self
+    }
+
+    /// Add a currency, replacing any existing currency with the same name.
+    pub fn add_currency(&mut self, name: impl Into<String>, limits: FixedDecimalAttribute) {
+        self.currencies.insert(name.into(), limits);
+    }
+
+    /// Get the attribute backing a currency.
+    #[must_use]
+    pub fn currency(&self, name: &str) -> Option<&FixedDecimalAttribute> {
+        self.currencies.get(name)
+    }
+
+    /// Iterate over the currencies, in name order.
+    pub fn currencies(&self) -> impl Iterator<Item = (&str, &FixedDecimalAttribute)> {
+        self.currencies
+            .iter()
+            .map(|(name, attribute)| (name.as_str(), attribute))
+    }
+
+    /// Get the balance of a currency, in units.
+    #[must_use]
+    pub fn balance(&self, name: &str) -> Option<i64> {
+        self.currencies
+            .get(name)
+            .map(FixedDecimalAttribute::current_value)
+    }
+
+    /// Check if `amount` of a currency can be spent without going below its minimum.
+    #[must_use]
+    pub fn can_afford(&self, name: &str, amount: i64) -> bool {
+        amount >= 0
+            && self.currencies.get(name).is_some_and(|attribute| {
+                attribute.current_value().saturating_sub(attribute.min) >= amount
+            })
+    }
+
+    /// Deposit an amount of a currency. Anything above the currency's maximum is lost.
+    ///
+    /// Returns the amount actually deposited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wallet does not hold the currency, or if the amount is negative.
+    pub fn deposit(&mut self, name: &str, amount: i64) -> Result<i64, AttributeError> {
+        Self::check_amount(name, amount)?;
+        let attribute = self.attribute_mut(name)?;
+        let before = attribute.current_value();
+        *attribute += amount;
+        let deposited = attribute.current_value() - before;
+        let balance = attribute.current_value();
+
+        self.record(name, deposited, balance);
+        Ok(deposited)
+    }
+
+    /// Spend an amount of a currency, failing without any change if there isn't enough.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wallet does not hold the currency, the amount is negative, or spending would go below
+    /// its minimum.
+    pub fn try_spend(&mut self, name: &str, amount: i64) -> Result<(), AttributeError> {
+        Self::check_amount(name, amount)?;
+        let attribute = self.attribute_mut(name)?;
+        let available = attribute.current_value().saturating_sub(attribute.min);
+        if amount > available {
+            return Err(AttributeError::InsufficientFunds(
+                name.to_string(),
+                amount,
+                available,
+            ));
+        }
+
+        *attribute -= amount;
+        let balance = attribute.current_value();
+
+        self.record(name, -amount, balance);
+        Ok(())
+    }
+
+    /// Exchange `amount` of one currency for another at the given rate.
+    ///
+    /// The exchange either happens completely or not at all: it fails if there isn't enough of the source currency,
+    /// or if the target currency can't hold the converted amount. Returns the amount of the target currency received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either currency is unknown, the amount is negative, there are insufficient funds, or the
+    /// target currency would exceed its maximum.
+    pub fn exchange(
+        &mut self,
+        from: &str,
+        to: &str,
+        amount: i64,
+        rate: ExchangeRate,
+    ) -> Result<i64, AttributeError> {
+        Self::check_amount(from, amount)?;
+        let converted = rate.convert(amount);
+
+        let target = self.attribute_mut(to)?;
+        let room = target.max.saturating_sub(target.current_value());
+        if converted > room {
+            return Err(AttributeError::InsufficientCapacity(
+                to.to_string(),
+                converted,
+                room,
+            ));
+        }
+
+        self.try_spend(from, amount)?;
+        self.deposit(to, converted)
+    }
+
+    /// Take the transactions recorded since the last call, oldest first.
+    pub fn drain_transactions(&mut self) -> Vec<Transaction> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Get a currency's attribute mutably, or fail if it is unknown.
+    fn attribute_mut(&mut self, name: &str) -> Result<&mut FixedDecimalAttribute, AttributeError> {
+        self.currencies
+            .get_mut(name)
+            .ok_or_else(|| AttributeError::UnknownCurrency(name.to_string()))
+    }
+
+    /// Reject negative amounts, which would turn a deposit into a spend or the other way around.
+    fn check_amount(name: &str, amount: i64) -> Result<(), AttributeError> {
+        if amount < 0 {
+            return Err(AttributeError::AttributeError(format!(
+                "Negative amount {amount} of {name}."
+            )));
+        }
+        Ok(())
+    }
+
+    /// Record a transaction, skipping ones that didn't change anything.
+    fn record(&mut self, name: &str, amount: i64, balance: i64) {
+        if amount != 0 {
+            self.pending.push(Transaction {
+                currency: name.to_string(),
+                amount,
+                balance,
+            });
+        }
+    }
+}
+
+/// Drain the recorded transactions of every `Wallet`, sending a `WalletTransaction` event for each.
+pub fn emit_wallet_transactions(
+    mut query: Query<(Entity, &mut Wallet)>,
+    mut events: EventWriter<WalletTransaction>,
+) {
+    for (entity, mut wallet) in &mut query {
+        if wallet.pending.is_empty() {
+            continue;
+        }
+        events.send_batch(wallet.drain_transactions().into_iter().map(|transaction| {
+            WalletTransaction {
+                entity,
+                transaction,
+            }
+        }));
+    }
+}
@@ -0,0 +1,90 @@
+//! # Watermarks
+//!
+//! This module contains the `Watermarks` component and the system that updates it.
+//!
+//! Add `Watermarks` next to an `IntegerAttribute` to opt in. It records the lowest and highest values the attribute
+//! has reached since the watermarks were last reset, which covers achievements such as "survive a fight at 1 HP" and
+//! post-fight summaries. The `update_watermarks` system only looks at attributes that Bevy change detection reports
+//! as changed, so untouched attributes cost nothing. Values that only exist between two runs of the system (for
+//! example a hit and a heal in the same frame) are not seen; call `observe` directly where that matters.
+
+use bevy_ecs::{component::Component, query::Changed, system::Query};
+use serde::{Deserialize, Serialize};
+
+use crate::{AttributeComponent, IntegerAttribute};
+
+/// The lowest and highest values an attribute has reached since the last reset.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{watermarks::Watermarks, IntegerAttribute};
+///
+/// let mut health = IntegerAttribute::new(100);
+/// let mut watermarks = Watermarks::new(&health);
+///
+/// health -= 99;
+/// watermarks.observe(&health);
+/// health += 50;
+/// watermarks.observe(&health);
+///
+/// assert_eq!(watermarks.low_watermark(), 1);
+/// assert_eq!(watermarks.high_watermark(), 100);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Component)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Watermarks {
+    /// The lowest value seen.
+    low: i32,
+    /// The highest value seen.
+    high: i32,
+}
+
+impl Watermarks {
+    /// Start tracking from the attribute's current value.
+    #[must_use]
+    pub const fn new(attribute: &IntegerAttribute) -> Self {
+        let current = attribute.current_value();
+        Self {
+            low: current,
+            high: current,
+        }
+    }
+
+    /// The lowest value seen since the last reset.
+    #[must_use]
+    pub const fn low_watermark(&self) -> i32 {
+        self.low
+    }
+
+    /// The highest value seen since the last reset.
+    #[must_use]
+    pub const fn high_watermark(&self) -> i32 {
+        self.high
+    }
+
+    /// Record the attribute's current value, updating the watermarks if it is a new low or high.
+    pub fn observe(&mut self, attribute: &IntegerAttribute) {
+        let current = attribute.current_value();
+        self.low = self.low.min(current);
+        self.high = self.high.max(current);
+    }
+
+    /// Forget the previous lows and highs, starting again from the attribute's current value.
+    pub const fn reset_watermarks(&mut self, attribute: &IntegerAttribute) {
+        *self = Self::new(attribute);
+    }
+}
+
+/// Update the `Watermarks` of every attribute `T` that changed since the system last ran.
+pub fn update_watermarks<T: AttributeComponent>(
+    mut query: Query<(&T, &mut Watermarks), Changed<T>>,
+) {
+    for (attribute, mut watermarks) in &mut query {
+        let attribute = attribute.attribute();
+        let current = attribute.current_value();
+        if current < watermarks.low_watermark() || current > watermarks.high_watermark() {
+            watermarks.observe(attribute);
+        }
+    }
+}
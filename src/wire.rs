@@ -0,0 +1,226 @@
+//! # Wire Format
+//!
+//! This module contains the `WireFormat` trait, a compact binary encoding for sending attributes over the network.
+//!
+//! The serde representation of the library's types is meant to be human-readable and stable for save files and
+//! content. The wire format is the opposite: every field is written as a fixed-width little-endian integer or float,
+//! enum discriminants are written as a single `u8`, and there are no field names or length prefixes. Every value of a
+//! type encodes to exactly `WIRE_SIZE` bytes, which makes the encoding easy to embed as a fixed-size byte array in
+//! `bincode` or `postcard` messages.
+//!
+//! Decoding validates the data, so a corrupted or malicious packet produces an `AttributeError` rather than an
+//! attribute that breaks its own invariants: a current value outside of the minimum and maximum is rejected rather
+//! than clamped, and timers must be finite and non-negative.
+
+use crate::{
+    AttributeError, DownedState, FixedDecimalAttribute, FloatAttribute, IntegerAttribute, LifeState,
+};
+
+/// A fixed-width, little-endian binary encoding.
+///
+/// # Example
+///
+/// ```rust
+/// use nwest_shared_component_library::{wire::WireFormat, IntegerAttribute};
+///
+/// let health = IntegerAttribute::new(100) - 25;
+/// let bytes = health.to_bytes();
+/// assert_eq!(bytes.len(), IntegerAttribute::WIRE_SIZE);
+///
+/// let received = IntegerAttribute::from_bytes(&bytes).expect("Failed to decode");
+/// assert_eq!(received, 75);
+/// ```
+pub trait WireFormat: Sized {
+    /// The number of bytes every value of this type encodes to.
+    const WIRE_SIZE: usize;
+
+    /// Append the encoded value to `buffer`. Exactly `WIRE_SIZE` bytes are written.
+    fn write_bytes(&self, buffer: &mut Vec<u8>);
+
+    /// Decode a value from the first `WIRE_SIZE` bytes of `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than `WIRE_SIZE` bytes, or if the decoded value is invalid.
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError>;
+
+    /// Encode the value into a new buffer of exactly `WIRE_SIZE` bytes.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(Self::WIRE_SIZE);
+        self.write_bytes(&mut buffer);
+        buffer
+    }
+
+    /// Decode a value from a buffer of exactly `WIRE_SIZE` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is not exactly `WIRE_SIZE` bytes long, or if the decoded value is invalid.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        if bytes.len() != Self::WIRE_SIZE {
+            return Err(AttributeError::ConversionError(format!(
+                "Expected {} bytes but got {}.",
+                Self::WIRE_SIZE,
+                bytes.len()
+            )));
+        }
+        Self::read_bytes(bytes)
+    }
+}
+
+/// Read the `N` bytes at `offset`, or fail if the buffer is too short.
+pub(crate) fn read_array<const N: usize>(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<[u8; N], AttributeError> {
+    bytes
+        .get(offset..offset + N)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or_else(|| {
+            AttributeError::ConversionError(format!(
+                "Buffer too short: needed {} bytes but got {}.",
+                offset + N,
+                bytes.len()
+            ))
+        })
+}
+
+/// Read a duration in seconds at `offset`, failing if it is NaN, infinite, or negative.
+fn read_seconds(bytes: &[u8], offset: usize, name: &str) -> Result<f32, AttributeError> {
+    let seconds = f32::from_le_bytes(read_array(bytes, offset)?);
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(AttributeError::ConversionError(format!(
+            "{name} must be a finite, non-negative number of seconds. {seconds}"
+        )));
+    }
+    Ok(seconds)
+}
+
+impl WireFormat for IntegerAttribute {
+    const WIRE_SIZE: usize = 12;
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.min.to_le_bytes());
+        buffer.extend_from_slice(&self.max.to_le_bytes());
+        buffer.extend_from_slice(&self.current.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        let current = i32::from_le_bytes(read_array(bytes, 8)?);
+        let attribute = Self::new_as_defined(
+            i32::from_le_bytes(read_array(bytes, 0)?),
+            i32::from_le_bytes(read_array(bytes, 4)?),
+            current,
+        )?;
+        if attribute.current != current {
+            return Err(AttributeError::OutOfBounds(
+                i64::from(current),
+                i64::from(attribute.min),
+                i64::from(attribute.max),
+            ));
+        }
+        Ok(attribute)
+    }
+}
+
+impl<const DECIMALS: u32> WireFormat for FixedDecimalAttribute<DECIMALS> {
+    const WIRE_SIZE: usize = 24;
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.min.to_le_bytes());
+        buffer.extend_from_slice(&self.max.to_le_bytes());
+        buffer.extend_from_slice(&self.current.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        let current = i64::from_le_bytes(read_array(bytes, 16)?);
+        let attribute = Self::new_as_defined(
+            i64::from_le_bytes(read_array(bytes, 0)?),
+            i64::from_le_bytes(read_array(bytes, 8)?),
+            current,
+        )?;
+        if attribute.current != current {
+            return Err(AttributeError::OutOfBounds(
+                current,
+                attribute.min,
+                attribute.max,
+            ));
+        }
+        Ok(attribute)
+    }
+}
+
+impl WireFormat for FloatAttribute {
+    const WIRE_SIZE: usize = 12;
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.min.to_le_bytes());
+        buffer.extend_from_slice(&self.max.to_le_bytes());
+        buffer.extend_from_slice(&self.current.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        let current = f32::from_le_bytes(read_array(bytes, 8)?);
+        let attribute = Self::new_as_defined(
+            f32::from_le_bytes(read_array(bytes, 0)?),
+            f32::from_le_bytes(read_array(bytes, 4)?),
+            current,
+        )?;
+        if current < attribute.min || current > attribute.max {
+            return Err(AttributeError::ConversionError(format!(
+                "Current value outside of minimum and maximum. {} <= {current} <= {}",
+                attribute.min, attribute.max
+            )));
+        }
+        Ok(attribute)
+    }
+}
+
+/// `LifeState` is written as a `u8` discriminant followed by the remaining bleed-out time, which is zero for the
+/// states that don't carry one.
+impl WireFormat for LifeState {
+    const WIRE_SIZE: usize = 5;
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        let (discriminant, remaining) = match self {
+            Self::Alive => (0_u8, 0.0_f32),
+            Self::Downed { remaining } => (1, *remaining),
+            Self::Dead => (2, 0.0),
+        };
+        buffer.push(discriminant);
+        buffer.extend_from_slice(&remaining.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        let [discriminant] = read_array(bytes, 0)?;
+        match discriminant {
+            0 => Ok(Self::Alive),
+            1 => Ok(Self::Downed {
+                remaining: read_seconds(bytes, 1, "Bleed-out time remaining")?,
+            }),
+            2 => Ok(Self::Dead),
+            _ => Err(AttributeError::ConversionError(format!(
+                "Unknown LifeState discriminant {discriminant}."
+            ))),
+        }
+    }
+}
+
+impl WireFormat for DownedState {
+    const WIRE_SIZE: usize = LifeState::WIRE_SIZE + 8;
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        self.state.write_bytes(buffer);
+        buffer.extend_from_slice(&self.bleed_out_duration.to_le_bytes());
+        buffer.extend_from_slice(&self.revive_health.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Result<Self, AttributeError> {
+        let offset = LifeState::WIRE_SIZE;
+        Ok(Self {
+            state: LifeState::read_bytes(bytes)?,
+            bleed_out_duration: read_seconds(bytes, offset, "Bleed-out duration")?,
+            revive_health: i32::from_le_bytes(read_array(bytes, offset + 4)?),
+        })
+    }
+}
@@ -0,0 +1,81 @@
+//! Tests for `Accumulator` and `KahanAccumulator`.
+
+use nwest_shared_component_library::accumulator::{Accumulator, KahanAccumulator};
+
+#[test]
+fn test_accumulator_past_u64() {
+    let mut total = Accumulator::new();
+    for _ in 0..4 {
+        total.add(u64::MAX);
+    }
+    assert_eq!(total.total(), 4 * u128::from(u64::MAX));
+    assert_eq!(total.count(), 4);
+}
+
+#[test]
+fn test_accumulator_mean_and_take() {
+    let mut total = Accumulator::new();
+    assert_eq!(total.mean(), None);
+    total.add(10);
+    total.add(20);
+    assert!((total.mean().expect("Missing mean") - 15.0).abs() < f64::EPSILON);
+    assert_eq!(total.take(), 30);
+    assert_eq!(total, Accumulator::new());
+}
+
+#[test]
+fn test_accumulator_merge() {
+    let mut lifetime = Accumulator::new();
+    lifetime.add(100);
+    let mut session = Accumulator::new();
+    session.add(5);
+    session.add(7);
+    lifetime.merge(&session);
+    assert_eq!(lifetime.total(), 112);
+    assert_eq!(lifetime.count(), 3);
+}
+
+#[test]
+fn test_kahan_precision() {
+    let mut total = KahanAccumulator::new();
+    let mut naive = 0.0_f64;
+    for _ in 0..10_000_000 {
+        total.add(0.1);
+        naive += 0.1;
+    }
+    assert!((total.total() - 1_000_000.0).abs() < 1e-6);
+    assert!((naive - 1_000_000.0).abs() > 1e-6);
+}
+
+#[test]
+fn test_kahan_ignores_non_finite() {
+    let mut total = KahanAccumulator::new();
+    total.add(2.5);
+    total.add(f64::NAN);
+    total.add(f64::INFINITY);
+    assert!((total.total() - 2.5).abs() < f64::EPSILON);
+    assert_eq!(total.count(), 1);
+}
+
+#[test]
+fn test_kahan_merge() {
+    let mut lifetime = KahanAccumulator::new();
+    lifetime.add(1e16);
+    let mut session = KahanAccumulator::new();
+    for _ in 0..100 {
+        session.add(1.0);
+    }
+    lifetime.merge(&session);
+    assert!((lifetime.total() - (1e16 + 100.0)).abs() < 1.0);
+    assert_eq!(lifetime.count(), 101);
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let mut total = Accumulator::new();
+    total.add(u64::MAX);
+    total.add(u64::MAX);
+    let json = serde_json::to_string(&total).expect("Failed to serialize");
+    let decoded: Accumulator = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(decoded, total);
+}
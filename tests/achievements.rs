@@ -0,0 +1,104 @@
+//! Tests for the `Achievements` component.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    accumulator::Accumulator,
+    achievements::{
+        evaluate_achievements, AchievementCondition, AchievementContext, AchievementUnlocked,
+        Achievements,
+    },
+    IntegerAttribute, Level, Watermarks,
+};
+
+#[test]
+fn test_conditions() {
+    let health = IntegerAttribute::new(100);
+    let mut total = Accumulator::new();
+    total.add(10_000);
+    let level = Level::new(5);
+    let context = AchievementContext {
+        attribute: Some(&health),
+        accumulator: Some(&total),
+        level: Some(&level),
+        ..Default::default()
+    };
+
+    assert!(AchievementCondition::AttributeAtLeast(100).is_met(&context));
+    assert!(!AchievementCondition::AttributeAtMost(99).is_met(&context));
+    assert!(AchievementCondition::TotalAtLeast(10_000).is_met(&context));
+    assert!(AchievementCondition::LevelAtLeast(5).is_met(&context));
+    assert!(!AchievementCondition::LevelAtLeast(6).is_met(&context));
+    // The entity has no watermarks, so watermark conditions are never met.
+    assert!(!AchievementCondition::LowWatermarkAtMost(i32::MAX).is_met(&context));
+}
+
+#[test]
+fn test_combinators() {
+    let context = AchievementContext::default();
+    assert!(AchievementCondition::AllOf(Vec::new()).is_met(&context));
+    assert!(!AchievementCondition::AnyOf(Vec::new()).is_met(&context));
+
+    let level = Level::new(3);
+    let context = AchievementContext {
+        level: Some(&level),
+        ..Default::default()
+    };
+    let either = AchievementCondition::AnyOf(vec![
+        AchievementCondition::LevelAtLeast(10),
+        AchievementCondition::LevelAtLeast(2),
+    ]);
+    let both = AchievementCondition::AllOf(vec![
+        AchievementCondition::LevelAtLeast(10),
+        AchievementCondition::LevelAtLeast(2),
+    ]);
+    assert!(either.is_met(&context));
+    assert!(!both.is_met(&context));
+}
+
+#[test]
+fn test_unlocks_once() {
+    let mut achievements = Achievements::new()
+        .with("veteran", AchievementCondition::LevelAtLeast(10))
+        .with("novice", AchievementCondition::LevelAtLeast(2));
+    let level = Level::new(2);
+    let context = AchievementContext {
+        level: Some(&level),
+        ..Default::default()
+    };
+
+    assert_eq!(achievements.evaluate(&context), vec!["novice".to_string()]);
+    assert!(achievements.evaluate(&context).is_empty());
+    assert!(achievements.is_unlocked("novice"));
+    assert!(!achievements.is_unlocked("veteran"));
+    assert!(!achievements.all_unlocked());
+    assert_eq!(achievements.iter().count(), 2);
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    world.init_resource::<Events<AchievementUnlocked>>();
+    let mut health = IntegerAttribute::new(100);
+    let mut watermarks = Watermarks::new(&health);
+    health -= 99;
+    watermarks.observe(&health);
+    let achievements = Achievements::new()
+        .with("close_call", AchievementCondition::LowWatermarkAtMost(1))
+        .with("full", AchievementCondition::AttributeAtLeast(100));
+    let entity = world.spawn((health, watermarks, achievements)).id();
+
+    world.run_system_once(evaluate_achievements);
+    world.run_system_once(evaluate_achievements);
+
+    let events: Vec<AchievementUnlocked> = world
+        .resource_mut::<Events<AchievementUnlocked>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        events,
+        vec![AchievementUnlocked {
+            entity,
+            id: "close_call".to_string()
+        }]
+    );
+}
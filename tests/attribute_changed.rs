@@ -0,0 +1,62 @@
+//! Tests for the `AttributeChanged` event.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    attribute_changed::{emit_attribute_changes, AttributeChangeTracker},
+    AttributeChanged, IntegerAttribute,
+};
+
+fn drain(world: &mut World) -> Vec<AttributeChanged> {
+    world
+        .resource_mut::<Events<AttributeChanged>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_observe() {
+    let mut tracker = AttributeChangeTracker::new();
+    let mut attribute = IntegerAttribute::new(10);
+    assert_eq!(tracker.observe(&attribute), None);
+    assert_eq!(tracker.last(), Some(10));
+    assert_eq!(tracker.observe(&attribute), None);
+    attribute -= 3;
+    assert_eq!(tracker.observe(&attribute), Some(10));
+    assert_eq!(tracker.last(), Some(7));
+}
+
+#[test]
+fn test_system_emits_on_change() {
+    let mut world = World::new();
+    world.init_resource::<Events<AttributeChanged>>();
+    let entity = world
+        .spawn((IntegerAttribute::new(100), AttributeChangeTracker::new()))
+        .id();
+    let untracked = world.spawn(IntegerAttribute::new(100)).id();
+
+    world.run_system_once(emit_attribute_changes::<IntegerAttribute>);
+    assert!(drain(&mut world).is_empty());
+
+    for target in [entity, untracked] {
+        *world
+            .get_mut::<IntegerAttribute>(target)
+            .expect("Missing attribute") -= 25;
+    }
+    world.run_system_once(emit_attribute_changes::<IntegerAttribute>);
+    assert_eq!(
+        drain(&mut world),
+        vec![AttributeChanged {
+            entity,
+            old: 100,
+            new: 75
+        }]
+    );
+
+    // Touching the attribute without changing the value doesn't send an event.
+    world
+        .get_mut::<IntegerAttribute>(entity)
+        .expect("Missing attribute")
+        .set_value(75);
+    world.run_system_once(emit_attribute_changes::<IntegerAttribute>);
+    assert!(drain(&mut world).is_empty());
+}
@@ -0,0 +1,91 @@
+//! Tests for the `BoundedAttribute` component and its `OverflowPolicy`.
+
+use nwest_shared_component_library::{
+    AttributeError, BoundedAttribute, IntegerAttribute, OverflowPolicy,
+};
+
+fn bounded(policy: OverflowPolicy) -> BoundedAttribute {
+    let attribute = IntegerAttribute::new_as_defined(0, 10, 5).expect("Failed to create attribute");
+    BoundedAttribute::new(attribute, policy)
+}
+
+#[test]
+fn test_clamp() {
+    let mut attribute = bounded(OverflowPolicy::Clamp);
+    assert_eq!(attribute.set_value(20), Ok(10));
+    assert_eq!(attribute.add_value(-30), Ok(0));
+
+    attribute += i32::MAX;
+    assert_eq!(attribute.current_value(), 10);
+    attribute *= i32::MIN;
+    assert_eq!(attribute.current_value(), 0);
+}
+
+#[test]
+fn test_error() {
+    let mut attribute = bounded(OverflowPolicy::Error);
+    assert_eq!(
+        attribute.set_value(11),
+        Err(AttributeError::OutOfBounds(11, 0, 10))
+    );
+    assert_eq!(
+        attribute.add_value(-6),
+        Err(AttributeError::OutOfBounds(-1, 0, 10))
+    );
+    assert_eq!(attribute.current_value(), 5);
+    assert_eq!(attribute.add_value(5), Ok(10));
+
+    // The operators can't return the error, so the change is dropped.
+    attribute += 1;
+    assert_eq!(attribute.current_value(), 10);
+    attribute -= 4;
+    assert_eq!(attribute.current_value(), 6);
+    attribute *= 2;
+    assert_eq!(attribute.current_value(), 6);
+    attribute /= 3;
+    assert_eq!(attribute.current_value(), 2);
+}
+
+#[test]
+fn test_wrap_around() {
+    let mut attribute = bounded(OverflowPolicy::WrapAround);
+    assert_eq!(attribute.set_value(11), Ok(0));
+    assert_eq!(attribute.add_value(-1), Ok(10));
+    assert_eq!(attribute.add_value(23), Ok(0));
+
+    attribute -= 12;
+    assert_eq!(attribute.current_value(), 10);
+    attribute *= 3;
+    assert_eq!(attribute.current_value(), 8);
+}
+
+#[test]
+fn test_wrap_around_full_range() {
+    let attribute = IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, i32::MAX)
+        .expect("Failed to create attribute");
+    let mut attribute = BoundedAttribute::new(attribute, OverflowPolicy::WrapAround);
+    attribute += 1;
+    assert_eq!(attribute.current_value(), i32::MIN);
+    attribute -= 1;
+    assert_eq!(attribute.current_value(), i32::MAX);
+}
+
+#[test]
+fn test_from_attribute() {
+    let attribute = BoundedAttribute::from(IntegerAttribute::new(100));
+    assert_eq!(attribute.policy, OverflowPolicy::Clamp);
+    assert_eq!(attribute.current_value(), 100);
+}
+
+#[test]
+fn test_serialization() {
+    let attribute = bounded(OverflowPolicy::WrapAround);
+    let json = serde_json::to_string(&attribute).expect("Failed to serialize");
+    assert_eq!(
+        json,
+        r#"{"attribute":{"max":10,"min":0,"current":5},"policy":"WrapAround"}"#
+    );
+    let loaded: BoundedAttribute = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(loaded, attribute);
+    assert_eq!(loaded.policy, OverflowPolicy::WrapAround);
+}
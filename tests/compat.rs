@@ -0,0 +1,16 @@
+//! Tests for the `compat` module.
+
+use nwest_shared_component_library::compat::v0_1;
+
+#[test]
+fn test_v0_1_paths() {
+    let from_root: v0_1::IntegerAttribute = v0_1::integer_attribute::IntegerAttribute::new(10);
+    let current: nwest_shared_component_library::IntegerAttribute = from_root;
+    assert_eq!(current, 10);
+
+    let error = v0_1::errors::AttributeError::MinGreaterThanMax(1, 0);
+    assert_eq!(
+        v0_1::IntegerAttribute::new_as_defined(1, 0, 0).map(i32::from),
+        Err::<i32, v0_1::AttributeError>(error)
+    );
+}
@@ -0,0 +1,104 @@
+//! Tests for the `Cooldown` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{cooldown::tick_cooldowns, Cooldown};
+
+#[test]
+fn test_new_is_ready() {
+    let cooldown = Cooldown::new(2.0);
+    assert!(cooldown.is_ready());
+    assert_eq!(cooldown.charges(), 1);
+    assert_eq!(cooldown.max_charges(), 1);
+    assert!((cooldown.progress() - 1.0).abs() < f32::EPSILON);
+    assert_eq!(Cooldown::new(1.0).with_charges(0).max_charges(), 1);
+}
+
+#[test]
+fn test_trigger_and_recharge() {
+    let mut cooldown = Cooldown::new(2.0);
+    assert!(cooldown.trigger());
+    assert!(!cooldown.trigger());
+    assert!((cooldown.remaining() - 2.0).abs() < f32::EPSILON);
+
+    assert_eq!(cooldown.tick(1.5), 0);
+    assert!(!cooldown.is_ready());
+    assert!((cooldown.progress() - 0.75).abs() < f32::EPSILON);
+
+    assert_eq!(cooldown.tick(0.5), 1);
+    assert!(cooldown.is_ready());
+    assert!(cooldown.remaining().abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_charges_recharge_one_at_a_time() {
+    let mut cooldown = Cooldown::new(1.0).with_charges(3);
+    assert!(cooldown.trigger());
+    assert!(cooldown.trigger());
+    assert!(cooldown.trigger());
+    assert!(!cooldown.is_ready());
+
+    assert_eq!(cooldown.tick(1.25), 1);
+    assert!((cooldown.remaining() - 0.75).abs() < f32::EPSILON);
+
+    // Spending a charge mid-recharge doesn't restart the timer.
+    assert!(cooldown.trigger());
+    assert!((cooldown.remaining() - 0.75).abs() < f32::EPSILON);
+
+    assert_eq!(cooldown.tick(100.0), 3);
+    assert_eq!(cooldown.charges(), 3);
+    assert!(cooldown.remaining().abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_zero_duration_recharges_immediately() {
+    let mut cooldown = Cooldown::new(0.0).with_charges(2);
+    cooldown.trigger();
+    cooldown.trigger();
+    assert_eq!(cooldown.tick(0.0), 2);
+}
+
+#[test]
+fn test_reset() {
+    let mut cooldown = Cooldown::new(5.0).with_charges(2);
+    cooldown.trigger();
+    cooldown.reset();
+    assert_eq!(cooldown.charges(), 2);
+    assert!(cooldown.remaining().abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let mut cooldown = Cooldown::new(1.0);
+    cooldown.trigger();
+    let entity = world.spawn(cooldown).id();
+
+    world.run_system_once_with(1.0, tick_cooldowns);
+
+    assert!(world
+        .get::<Cooldown>(entity)
+        .is_some_and(Cooldown::is_ready));
+}
+
+#[test]
+fn test_serialization() {
+    let mut dash = Cooldown::new(3.0).with_charges(2);
+    dash.trigger();
+    let json = serde_json::to_string(&dash).expect("Failed to serialize");
+    let loaded: Cooldown = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(loaded, dash);
+
+    // More charges than the maximum would underflow when recharging, so it is rejected.
+    let invalid = r#"{"duration":3.0,"remaining":0.0,"max_charges":1,"charges":2}"#;
+    assert!(serde_json::from_str::<Cooldown>(invalid).is_err());
+    let no_charges = r#"{"duration":3.0,"remaining":0.0,"max_charges":0,"charges":0}"#;
+    assert!(serde_json::from_str::<Cooldown>(no_charges).is_err());
+
+    // A negative or non-finite timer would bring a charge back on the next tick. `1e39` overflows to infinity.
+    for (duration, remaining) in [("3.0", "-1.0"), ("-3.0", "0.0"), ("3.0", "1e39")] {
+        let invalid = format!(
+            r#"{{"duration":{duration},"remaining":{remaining},"max_charges":2,"charges":1}}"#
+        );
+        assert!(serde_json::from_str::<Cooldown>(&invalid).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Tests for `ScalingCurve`.
+
+use nwest_shared_component_library::curves::{CurvePoint, ScalingCurve};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "{actual} is not close to {expected}"
+    );
+}
+
+#[test]
+fn test_linear() {
+    let curve = ScalingCurve::Linear {
+        base: 10.0,
+        per_level: 2.0,
+    };
+    assert_close(curve.evaluate(0), 10.0);
+    assert_close(curve.evaluate(1), 10.0);
+    assert_close(curve.evaluate(11), 30.0);
+}
+
+#[test]
+fn test_exponential() {
+    let curve = ScalingCurve::Exponential {
+        base: 100.0,
+        factor: 1.1,
+    };
+    assert_close(curve.evaluate(1), 100.0);
+    assert_close(curve.evaluate(3), 121.0);
+}
+
+#[test]
+fn test_logistic() {
+    let curve = ScalingCurve::Logistic {
+        min: 0.0,
+        max: 50.0,
+        midpoint: 20.0,
+        steepness: 0.5,
+    };
+    assert_close(curve.evaluate(20), 25.0);
+    assert!(curve.evaluate(1) < 1.0);
+    assert!(curve.evaluate(60) > 49.9);
+    assert!(curve.evaluate(21) > curve.evaluate(20));
+}
+
+#[test]
+fn test_table() {
+    let curve = ScalingCurve::Table(vec![
+        CurvePoint {
+            level: 10,
+            value: 200.0,
+        },
+        CurvePoint {
+            level: 1,
+            value: 20.0,
+        },
+        CurvePoint {
+            level: 5,
+            value: 100.0,
+        },
+    ]);
+    assert_close(curve.evaluate(1), 20.0);
+    assert_close(curve.evaluate(3), 60.0);
+    assert_close(curve.evaluate(5), 100.0);
+    assert_close(curve.evaluate(8), 160.0);
+    assert_close(curve.evaluate(99), 200.0);
+    assert_close(ScalingCurve::Table(Vec::new()).evaluate(3), 0.0);
+}
+
+#[test]
+fn test_rounded_saturates() {
+    let curve = ScalingCurve::Exponential {
+        base: 1.0,
+        factor: 10.0,
+    };
+    assert_eq!(curve.evaluate_rounded(3), 100);
+    assert_eq!(curve.evaluate_rounded(40), i32::MAX);
+}
+
+#[test]
+fn test_attribute_at() {
+    let curve = ScalingCurve::Linear {
+        base: 50.0,
+        per_level: 10.5,
+    };
+    let attribute = curve.attribute_at(3);
+    assert_eq!(attribute.max, 71);
+    assert_eq!(attribute.current_value(), 71);
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let curve = ScalingCurve::Logistic {
+        min: 1.0,
+        max: 2.0,
+        midpoint: 3.0,
+        steepness: 4.0,
+    };
+    let json = serde_json::to_string(&curve).expect("Failed to serialize");
+    let decoded: ScalingCurve = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(decoded, curve);
+}
@@ -0,0 +1,84 @@
+//! Tests for the `Depletable` component and the `Depleted` marker.
+
+use bevy_ecs::{entity::Entity, event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    depletion::update_depletion, AttributeDepleted, Depletable, Depleted, DepletionPolicy,
+    IntegerAttribute,
+};
+
+fn world_with(policy: Option<DepletionPolicy>) -> (World, Entity) {
+    let mut world = World::new();
+    world.init_resource::<Events<AttributeDepleted>>();
+    let mut entity = world.spawn(IntegerAttribute::new(10));
+    if let Some(policy) = policy {
+        entity.insert(Depletable::new(policy));
+    }
+    let entity = entity.id();
+    world.run_system_once(update_depletion::<IntegerAttribute>);
+    (world, entity)
+}
+
+fn set(world: &mut World, entity: Entity, value: i32) {
+    if let Some(mut attribute) = world.get_mut::<IntegerAttribute>(entity) {
+        attribute.set_value(value);
+    }
+    world.run_system_once(update_depletion::<IntegerAttribute>);
+}
+
+fn events(world: &mut World) -> Vec<AttributeDepleted> {
+    world
+        .resource_mut::<Events<AttributeDepleted>>()
+        .drain()
+        .collect()
+}
+
+fn current(world: &World, entity: Entity) -> Option<i32> {
+    world
+        .get::<IntegerAttribute>(entity)
+        .map(|attribute| attribute.current)
+}
+
+#[test]
+fn test_depletion_marks_and_sends_once() {
+    let (mut world, entity) = world_with(Some(DepletionPolicy::Modifiable));
+    assert!(world.get::<Depleted>(entity).is_none());
+
+    set(&mut world, entity, 0);
+    assert!(world.get::<Depleted>(entity).is_some());
+    assert_eq!(events(&mut world), vec![AttributeDepleted { entity }]);
+
+    set(&mut world, entity, 0);
+    assert!(events(&mut world).is_empty());
+}
+
+#[test]
+fn test_modifiable_recovers() {
+    let (mut world, entity) = world_with(Some(DepletionPolicy::Modifiable));
+    set(&mut world, entity, 0);
+    set(&mut world, entity, 5);
+    assert!(world.get::<Depleted>(entity).is_none());
+    assert_eq!(current(&world, entity), Some(5));
+}
+
+#[test]
+fn test_locked_stays_at_minimum() {
+    let (mut world, entity) = world_with(Some(DepletionPolicy::Locked));
+    set(&mut world, entity, 0);
+    set(&mut world, entity, 5);
+    assert!(world.get::<Depleted>(entity).is_some());
+    assert_eq!(current(&world, entity), Some(0));
+
+    // Removing the marker, such as when resurrecting, unlocks the attribute.
+    world.entity_mut(entity).remove::<Depleted>();
+    set(&mut world, entity, 5);
+    assert_eq!(current(&world, entity), Some(5));
+    assert_eq!(events(&mut world).len(), 1);
+}
+
+#[test]
+fn test_opt_in() {
+    let (mut world, entity) = world_with(None);
+    set(&mut world, entity, 0);
+    assert!(world.get::<Depleted>(entity).is_none());
+    assert!(events(&mut world).is_empty());
+}
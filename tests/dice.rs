@@ -0,0 +1,102 @@
+//! Tests for the `dice` module.
+#![cfg(feature = "dice")]
+
+use nwest_shared_component_library::{dice::Roll, AttributeError};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_parse() {
+    assert_eq!("3d6+2".parse(), Roll::new(3, 6, 2));
+    assert_eq!("d20".parse(), Roll::new(1, 20, 0));
+    assert_eq!(" 2D8 - 1 ".parse(), Roll::new(2, 8, -1));
+    assert_eq!(
+        "3x6".parse::<Roll>(),
+        Err(AttributeError::ConversionError(
+            "Invalid dice notation '3x6'.".to_string()
+        ))
+    );
+    assert!("3d".parse::<Roll>().is_err());
+    assert!("3d6+".parse::<Roll>().is_err());
+    assert!("3d0".parse::<Roll>().is_err());
+}
+
+#[test]
+fn test_display_round_trip() {
+    for notation in ["3d6+2", "1d20", "2d8-1"] {
+        let roll: Roll = notation.parse().expect("Invalid dice");
+        assert_eq!(roll.to_string(), notation);
+    }
+}
+
+#[test]
+fn test_range() {
+    let roll = Roll::new(4, 6, -4).expect("Invalid dice");
+    assert_eq!(roll.min(), 0);
+    assert_eq!(roll.max(), 20);
+    let huge = Roll::new(Roll::MAX_COUNT, u32::MAX, 0).expect("Invalid dice");
+    assert_eq!(huge.max(), i32::MAX);
+    assert!(Roll::new(Roll::MAX_COUNT + 1, 6, 0).is_err());
+    assert!("4000000000d6".parse::<Roll>().is_err());
+}
+
+#[test]
+fn test_roll_is_in_range_and_seeded() {
+    let roll = Roll::new(3, 6, 2).expect("Invalid dice");
+    let mut rng = StdRng::seed_from_u64(42);
+    let totals: Vec<i32> = (0..100).map(|_| roll.roll(&mut rng)).collect();
+    assert!(totals.iter().all(|total| (5..=20).contains(total)));
+
+    let mut again = StdRng::seed_from_u64(42);
+    let repeated: Vec<i32> = (0..100).map(|_| roll.roll(&mut again)).collect();
+    assert_eq!(totals, repeated);
+}
+
+#[test]
+fn test_advantage_and_disadvantage() {
+    let roll = Roll::new(1, 20, 0).expect("Invalid dice");
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut check = StdRng::seed_from_u64(1);
+    for _ in 0..50 {
+        let first = roll.roll(&mut check);
+        let second = roll.roll(&mut check);
+        assert_eq!(roll.roll_with_advantage(&mut rng), first.max(second));
+    }
+
+    let mut rng = StdRng::seed_from_u64(2);
+    let mut check = StdRng::seed_from_u64(2);
+    for _ in 0..50 {
+        let first = roll.roll(&mut check);
+        let second = roll.roll(&mut check);
+        assert_eq!(roll.roll_with_disadvantage(&mut rng), first.min(second));
+    }
+}
+
+#[test]
+fn test_roll_attributes() {
+    let roll = Roll::new(3, 6, 0).expect("Invalid dice");
+    let mut rng = StdRng::seed_from_u64(3);
+
+    let hit_points = roll.roll_attribute(&mut rng);
+    assert_eq!(hit_points.min, 0);
+    assert_eq!(hit_points.max, hit_points.current);
+
+    let strength = roll.roll_ranged_attribute(&mut rng);
+    assert_eq!(strength.min, 3);
+    assert_eq!(strength.max, 18);
+    assert!((3..=18).contains(&strength.current));
+}
+
+#[test]
+fn test_serialization() {
+    let roll = Roll::new(3, 6, 2).expect("Invalid dice");
+    let json = serde_json::to_string(&roll).expect("Failed to serialize");
+    assert_eq!(json, r#"{"count":3,"sides":6,"modifier":2}"#);
+    let loaded: Roll = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(loaded, roll);
+
+    // Deserializing is checked the same as `Roll::new`.
+    assert!(serde_json::from_str::<Roll>(r#"{"count":1,"sides":0,"modifier":0}"#).is_err());
+    assert!(
+        serde_json::from_str::<Roll>(r#"{"count":4000000000,"sides":6,"modifier":0}"#).is_err()
+    );
+}
@@ -0,0 +1,92 @@
+//! Tests for the `DisplayedAttribute` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    displayed_attribute::{animate_displayed_attributes, DisplayedAttribute, Easing},
+    IntegerAttribute,
+};
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "{actual} is not close to {expected}"
+    );
+}
+
+#[test]
+fn test_easing_endpoints() {
+    for easing in [Easing::Linear, Easing::EaseOut, Easing::EaseInOut] {
+        assert_close(easing.apply(0.0), 0.0);
+        assert_close(easing.apply(1.0), 1.0);
+        assert_close(easing.apply(2.0), 1.0);
+    }
+    assert_close(Easing::EaseInOut.apply(0.5), 0.5);
+    assert!(Easing::EaseOut.apply(0.25) > Easing::Linear.apply(0.25));
+    assert!(Easing::EaseInOut.apply(0.25) < Easing::Linear.apply(0.25));
+}
+
+#[test]
+fn test_starts_at_current() {
+    let mut health = IntegerAttribute::new(100);
+    health.set_value(30);
+    let bar = DisplayedAttribute::new(&health, 1.0, Easing::EaseOut);
+    assert_close(bar.displayed(), 30.0);
+    assert!(!bar.is_animating());
+}
+
+#[test]
+fn test_retargets_from_displayed_value() {
+    let mut health = IntegerAttribute::new(100);
+    let mut bar = DisplayedAttribute::new(&health, 1.0, Easing::Linear);
+
+    health -= 100;
+    bar.update(&health, 0.5);
+    assert_close(bar.displayed(), 50.0);
+
+    // A heal mid-animation starts a new animation from 50.
+    health += 100;
+    bar.update(&health, 0.5);
+    assert_close(bar.displayed(), 75.0);
+    bar.update(&health, 10.0);
+    assert_close(bar.displayed(), 100.0);
+    assert!(!bar.is_animating());
+}
+
+#[test]
+fn test_zero_duration_snaps() {
+    let mut health = IntegerAttribute::new(100);
+    let mut bar = DisplayedAttribute::new(&health, 0.0, Easing::Linear);
+    health -= 25;
+    assert_close(bar.update(&health, 0.0), 75.0);
+}
+
+#[test]
+fn test_snap() {
+    let mut health = IntegerAttribute::new(100);
+    let mut bar = DisplayedAttribute::new(&health, 1.0, Easing::Linear);
+    health -= 60;
+    bar.update(&health, 0.1);
+    bar.snap(&health);
+    assert_close(bar.displayed(), 40.0);
+    assert!(!bar.is_animating());
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let health = IntegerAttribute::new(100);
+    let bar = DisplayedAttribute::new(&health, 2.0, Easing::Linear);
+    let entity = world.spawn((health, bar)).id();
+
+    world
+        .get_mut::<IntegerAttribute>(entity)
+        .expect("Missing attribute")
+        .set_value(0);
+    world.run_system_once_with(1.0, animate_displayed_attributes::<IntegerAttribute>);
+
+    let bar = world
+        .get::<DisplayedAttribute>(entity)
+        .expect("Missing displayed attribute");
+    assert_close(bar.displayed(), 50.0);
+    assert_eq!(bar.target(), 0);
+}
@@ -0,0 +1,91 @@
+//! Tests for the `DownedState` component and its systems.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    downed::{process_revive_requests, update_downed_state},
+    DownedEvent, DownedState, DownedTransition, IntegerAttribute, LifeState, ReviveRequest,
+};
+
+#[test]
+fn test_alive_to_downed() {
+    let mut health = IntegerAttribute::new(100);
+    let mut state = DownedState::new(5.0, 10);
+    assert_eq!(state.update(&health, 1.0), None);
+    health -= 100;
+    assert_eq!(state.update(&health, 1.0), Some(DownedTransition::Downed));
+    assert_eq!(state.state, LifeState::Downed { remaining: 5.0 });
+}
+
+#[test]
+fn test_bleed_out() {
+    let health =
+        IntegerAttribute::with_min_and_max(0, 0).expect("Failed to create IntegerAttribute");
+    let mut state = DownedState::new(2.0, 10);
+    assert_eq!(state.update(&health, 0.5), Some(DownedTransition::Downed));
+    assert_eq!(state.update(&health, 1.5), None);
+    assert_eq!(state.update(&health, 0.5), Some(DownedTransition::Died));
+    assert!(state.is_dead());
+    assert_eq!(state.update(&health, 0.5), None);
+}
+
+#[test]
+fn test_revive() {
+    let mut health = IntegerAttribute::new(100);
+    let mut state = DownedState::new(5.0, 30);
+    assert_eq!(state.revive(&mut health), None);
+    health -= 100;
+    state.update(&health, 0.0);
+    assert_eq!(state.revive(&mut health), Some(DownedTransition::Revived));
+    assert!(state.is_alive());
+    assert_eq!(health, 30);
+}
+
+#[test]
+fn test_revive_dead_is_ignored() {
+    let mut health = IntegerAttribute::new(0);
+    let mut state = DownedState::new(0.0, 30);
+    state.update(&health, 0.0);
+    state.update(&health, 0.0);
+    assert!(state.is_dead());
+    assert_eq!(state.revive(&mut health), None);
+    assert_eq!(health, 0);
+}
+
+#[test]
+fn test_systems_emit_events() {
+    let mut world = World::new();
+    world.init_resource::<Events<DownedEvent>>();
+    world.init_resource::<Events<ReviveRequest>>();
+    let entity = world
+        .spawn((IntegerAttribute::new(100) - 100, DownedState::new(5.0, 50)))
+        .id();
+
+    world.run_system_once_with(1.0, update_downed_state::<IntegerAttribute>);
+    world.send_event(ReviveRequest { entity });
+    world.run_system_once(process_revive_requests::<IntegerAttribute>);
+
+    let events: Vec<DownedEvent> = world
+        .resource_mut::<Events<DownedEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            DownedEvent {
+                entity,
+                transition: DownedTransition::Downed
+            },
+            DownedEvent {
+                entity,
+                transition: DownedTransition::Revived
+            },
+        ]
+    );
+    assert_eq!(
+        *world
+            .entity(entity)
+            .get::<IntegerAttribute>()
+            .expect("Missing health"),
+        50
+    );
+}
@@ -0,0 +1,56 @@
+//! Tests for editor hints.
+
+use nwest_shared_component_library::{
+    editor_hints::{EditorHint, EditorHints},
+    FixedDecimalAttribute, FloatAttribute, Health, IntegerAttribute, Percent,
+};
+
+#[test]
+fn test_integer_attribute() {
+    let attribute =
+        IntegerAttribute::new_as_defined(-10, 50, 0).expect("Failed to create attribute");
+    assert_eq!(attribute.editor_hint(), EditorHint::new(-10.0, 50.0, 1.0));
+    assert_eq!(
+        Health::new(100).editor_hint(),
+        EditorHint::new(0.0, 100.0, 1.0)
+    );
+}
+
+#[test]
+fn test_fixed_decimal_attribute() {
+    let gold = FixedDecimalAttribute::<2>::new(12_345);
+    let hint = gold.editor_hint();
+    assert!((hint.max - 123.45).abs() < 1e-9);
+    assert!((hint.step - 0.01).abs() < 1e-9);
+    assert!((hint.to_display(12_345.0) - 123.45).abs() < 1e-9);
+    assert!((hint.from_display(1.5) - 150.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_float_attribute() {
+    let hint = FloatAttribute::new(50.0).editor_hint();
+    assert!((hint.step - 0.5).abs() < f64::EPSILON);
+
+    let empty = FloatAttribute::new(0.0).editor_hint();
+    assert!((empty.step - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_percent() {
+    let hint = Percent::FULL.editor_hint();
+    assert!((hint.to_display(0.5) - 50.0).abs() < f64::EPSILON);
+    assert!((hint.from_display(50.0) - 0.5).abs() < f64::EPSILON);
+    assert_eq!(hint.unit.as_deref(), Some("%"));
+}
+
+#[test]
+fn test_serialization() {
+    let hint = Percent::ZERO.editor_hint();
+    let json = serde_json::to_string(&hint).expect("Failed to serialize");
+    assert_eq!(
+        json,
+        r#"{"min":0.0,"max":100.0,"step":0.1,"display_scale":100.0,"unit":"%"}"#
+    );
+    let loaded: EditorHint = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(loaded, hint);
+}
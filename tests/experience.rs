@@ -0,0 +1,137 @@
+//! Tests for the `Level` component and level curves.
+
+use nwest_shared_component_library::experience::{
+    ExponentialCurve, Level, LevelCurve, LinearCurve, TableCurve,
+};
+
+#[test]
+fn test_linear_curve() {
+    let curve = LinearCurve {
+        base: 100,
+        increment: 50,
+    };
+    assert_eq!(curve.experience_to_next(1), Some(100));
+    assert_eq!(curve.experience_to_next(3), Some(200));
+}
+
+#[test]
+fn test_exponential_curve() {
+    let curve = ExponentialCurve {
+        base: 100,
+        factor: 1.5,
+    };
+    assert_eq!(curve.experience_to_next(1), Some(100));
+    assert_eq!(curve.experience_to_next(3), Some(225));
+}
+
+#[test]
+fn test_table_curve() {
+    let curve = TableCurve {
+        thresholds: vec![10, 20],
+    };
+    assert_eq!(curve.experience_to_next(1), Some(10));
+    assert_eq!(curve.experience_to_next(2), Some(20));
+    assert_eq!(curve.experience_to_next(3), None);
+}
+
+#[test]
+fn test_add_experience_single_level() {
+    let curve = LinearCurve {
+        base: 100,
+        increment: 0,
+    };
+    let mut level = Level::default();
+    assert_eq!(level.add_experience(99, &curve), 0);
+    assert_eq!(level.add_experience(1, &curve), 1);
+    assert_eq!(level.level, 2);
+    assert_eq!(level.experience, 0);
+}
+
+#[test]
+fn test_add_experience_multiple_levels() {
+    let curve = TableCurve {
+        thresholds: vec![10, 20, 30],
+    };
+    let mut level = Level::default();
+    assert_eq!(level.add_experience(35, &curve), 2);
+    assert_eq!(level.level, 3);
+    assert_eq!(level.experience, 5);
+    assert_eq!(level.experience_remaining(&curve), Some(25));
+}
+
+#[test]
+fn test_max_level() {
+    let curve = TableCurve {
+        thresholds: vec![10],
+    };
+    let mut level = Level::default();
+    assert_eq!(level.add_experience(100, &curve), 1);
+    assert_eq!(level.level, 2);
+    assert_eq!(level.experience, 90);
+    assert_eq!(level.experience_remaining(&curve), None);
+    assert!((level.progress(&curve) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_progress() {
+    let curve = LinearCurve {
+        base: 100,
+        increment: 0,
+    };
+    let mut level = Level::new(0);
+    assert_eq!(level.level, 1);
+    level.add_experience(25, &curve);
+    assert!((level.progress(&curve) - 0.25).abs() < f32::EPSILON);
+}
+
+/// A linear curve that levels one step at a time, to check the closed form against.
+struct Stepwise(LinearCurve);
+
+impl LevelCurve for Stepwise {
+    fn experience_to_next(&self, level: u32) -> Option<u64> {
+        self.0.experience_to_next(level)
+    }
+}
+
+#[test]
+fn test_linear_closed_form_matches_stepwise() {
+    for (base, increment) in [(100, 50), (1, 0), (7, 3), (0, 5), (1_000, 0)] {
+        let curve = LinearCurve { base, increment };
+        for start in [1, 2, 17] {
+            for amount in [0, 1, 99, 100, 1_234, 99_999] {
+                let mut fast = Level::new(start);
+                let mut slow = Level::new(start);
+                assert_eq!(
+                    fast.add_experience(amount, &curve),
+                    slow.add_experience(amount, &Stepwise(curve)),
+                );
+                assert_eq!(fast, slow);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_linear_huge_grant() {
+    let curve = LinearCurve {
+        base: 1_000,
+        increment: 1_000,
+    };
+    let mut level = Level::default();
+    let gained = level.add_experience(u64::MAX, &curve);
+    assert_eq!(level.level, gained + 1);
+    let remaining = level
+        .experience_remaining(&curve)
+        .expect("Linear curves have no maximum");
+    assert!(remaining > 0);
+
+    // A flat curve runs out of levels before experience.
+    let flat = LinearCurve {
+        base: 1,
+        increment: 0,
+    };
+    let mut level = Level::default();
+    assert_eq!(level.add_experience(u64::MAX, &flat), u32::MAX - 1);
+    assert_eq!(level.level, u32::MAX);
+    assert_eq!(level.experience, u64::MAX - u64::from(u32::MAX - 1));
+}
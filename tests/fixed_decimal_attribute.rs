@@ -0,0 +1,167 @@
+//! Tests for the `FixedDecimalAttribute` struct.
+
+use nwest_shared_component_library::{AttributeError, FixedDecimalAttribute};
+
+type Gold = FixedDecimalAttribute<2>;
+
+#[test]
+fn test_new() {
+    let attribute = FixedDecimalAttribute::<3>::new(1_500);
+    assert_eq!(attribute.min, 0);
+    assert_eq!(attribute.max, 1_500);
+    assert_eq!(attribute.current, 1_500);
+    assert_eq!(FixedDecimalAttribute::<3>::SCALE, 1_000);
+}
+
+#[test]
+fn test_new_as_defined_error() {
+    assert_eq!(
+        Gold::new_as_defined(100, 0, 0),
+        Err(AttributeError::MinGreaterThanMaxUnits(100, 0))
+    );
+}
+
+#[test]
+fn test_parse_units() {
+    assert_eq!(Gold::parse_units("12.5"), Ok(1_250));
+    assert_eq!(Gold::parse_units("12.05"), Ok(1_205));
+    assert_eq!(Gold::parse_units("-0.25"), Ok(-25));
+    assert_eq!(Gold::parse_units("7"), Ok(700));
+    assert_eq!(Gold::parse_units(".5"), Ok(50));
+    assert!(Gold::parse_units("1.234").is_err());
+    assert!(Gold::parse_units("abc").is_err());
+    assert!(Gold::parse_units("").is_err());
+    assert!(Gold::parse_units(".").is_err());
+    assert!(Gold::parse_units("99999999999999999999").is_err());
+}
+
+#[test]
+fn test_format_units() {
+    assert_eq!(Gold::format_units(1_205), "12.05");
+    assert_eq!(Gold::format_units(-5), "-0.05");
+    assert_eq!(FixedDecimalAttribute::<0>::format_units(42), "42");
+}
+
+#[test]
+fn test_exact_addition() {
+    let mut gold = Gold::with_min_and_max(0, 1_000).expect("Failed to create attribute");
+    gold.set_value(0);
+    for _ in 0..10 {
+        gold += 10;
+    }
+    assert_eq!(gold, 100);
+    assert_eq!(gold.to_string(), "1.00");
+    assert_eq!(gold.whole(), 1);
+}
+
+#[test]
+fn test_clamping() {
+    let gold = Gold::new(500);
+    assert_eq!(gold + 100, 500);
+    assert_eq!(gold - 1_000, 0);
+    assert_eq!(gold * 3, 500);
+    assert_eq!(gold - i64::MAX, 0);
+}
+
+#[test]
+fn test_mul_ratio() {
+    let mut gold = Gold::new(10_000);
+    gold.set_value(999);
+    gold.mul_ratio(15, 100).expect("Failed to apply ratio");
+    // 9.99 * 0.15 = 1.4985, rounded to 1.50
+    assert_eq!(gold, 150);
+    assert_eq!(
+        gold.mul_ratio(1, 0),
+        Err(AttributeError::ConversionError(
+            "Cannot multiply by a ratio with a zero denominator.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_mul_ratio_negative() {
+    let mut attribute = Gold::with_min_and_max(-1_000, 1_000).expect("Failed to create attribute");
+    attribute.set_value(-5);
+    attribute.mul_ratio(1, 2).expect("Failed to apply ratio");
+    assert_eq!(attribute, -3);
+}
+
+#[test]
+fn test_set_min_max() {
+    let mut gold = Gold::new(1_000);
+    assert_eq!(
+        gold.set_max(-1),
+        Err(AttributeError::MaxLessThanMinUnits(-1, 0))
+    );
+    gold.set_max(500).expect("Failed to set max");
+    assert_eq!(gold, 500);
+    assert_eq!(
+        gold.set_min(600),
+        Err(AttributeError::MinGreaterThanMaxUnits(600, 500))
+    );
+    gold.set_min(100).expect("Failed to set min");
+    gold.set_value(0);
+    assert_eq!(gold, 100);
+}
+
+#[test]
+fn test_percentage() {
+    let mut gold = Gold::new(1_000);
+    gold.set_value(250);
+    assert!((gold.current_percentage() - 0.25).abs() < f32::EPSILON);
+    assert!((gold.to_f64() - 2.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_try_mutations() {
+    let mut gold = Gold::new(10_000);
+    assert_eq!(gold.try_subtract(2_500), Ok(7_500));
+    assert_eq!(
+        gold.try_add(2_501),
+        Err(AttributeError::OutOfBounds(10_001, 0, 10_000))
+    );
+    assert_eq!(gold.try_set_value(0), Ok(()));
+    assert_eq!(
+        gold.try_set_value(-1),
+        Err(AttributeError::OutOfBounds(-1, 0, 10_000))
+    );
+    assert_eq!(
+        gold.try_subtract(i64::MIN),
+        Err(AttributeError::OutOfBounds(i64::MAX, 0, 10_000))
+    );
+    assert_eq!(gold, 0);
+}
+
+#[test]
+fn test_deref() {
+    let gold = Gold::new(1_250);
+    assert_eq!(*gold, 1_250);
+    assert_eq!(gold.signum(), 1);
+    let units: &i64 = gold.as_ref();
+    assert_eq!(*units, 1_250);
+}
+
+static STARTING_GOLD: Gold = Gold::new(10_000);
+
+#[test]
+fn test_const_construction() {
+    assert_eq!(STARTING_GOLD.current_value(), 10_000);
+    assert_eq!(STARTING_GOLD.whole(), 100);
+}
+
+#[test]
+fn test_builder() {
+    let gold = Gold::builder()
+        .min(-1_000)
+        .max(10_000)
+        .current(250)
+        .build()
+        .expect("Failed to build");
+    assert_eq!(gold.min, -1_000);
+    assert_eq!(gold.max, 10_000);
+    assert_eq!(gold, 250);
+
+    let full = Gold::builder().max(500).build().expect("Failed to build");
+    assert_eq!(full, 500);
+    assert!(Gold::builder().min(1).build().is_err());
+}
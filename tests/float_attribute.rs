@@ -0,0 +1,118 @@
+//! Tests for the `FloatAttribute` struct.
+
+use std::ops::RangeBounds;
+
+use nwest_shared_component_library::{AttributeError, FloatAttribute};
+
+#[test]
+fn test_new() {
+    let attribute = FloatAttribute::new(10.0);
+    assert!(attribute.min.abs() < f32::EPSILON);
+    assert!((attribute.max - 10.0).abs() < f32::EPSILON);
+    assert_eq!(attribute, 10.0);
+
+    let negative = FloatAttribute::new(-5.0);
+    assert!((negative.min + 5.0).abs() < f32::EPSILON);
+    assert_eq!(FloatAttribute::new(f32::NAN), 0.0);
+}
+
+#[test]
+fn test_new_as_defined() {
+    let attribute = FloatAttribute::new_as_defined(1.0, 2.0, 3.0).expect("Failed to create");
+    assert_eq!(attribute, 2.0);
+    assert_eq!(
+        FloatAttribute::new_as_defined(2.0, 1.0, 1.5),
+        Err(AttributeError::MinGreaterThanMaxFloat(
+            "2".to_string(),
+            "1".to_string()
+        ))
+    );
+    assert!(FloatAttribute::new_as_defined(f32::NAN, 1.0, 1.0).is_err());
+    assert!(FloatAttribute::with_min_and_max(0.0, 4.0).is_ok());
+}
+
+#[test]
+fn test_set_value() {
+    let mut attribute = FloatAttribute::new(10.0);
+    attribute.set_value(2.5);
+    assert_eq!(attribute, 2.5);
+    attribute.set_value(20.0);
+    assert_eq!(attribute, 10.0);
+    attribute.set_value(f32::NAN);
+    assert_eq!(attribute, 10.0);
+}
+
+#[test]
+fn test_current_percentage() {
+    let attribute = FloatAttribute::new_as_defined(-10.0, 10.0, 5.0).expect("Failed to create");
+    assert!((attribute.current_percentage() - 0.75).abs() < f32::EPSILON);
+    let empty_range = FloatAttribute::new(0.0);
+    assert!((empty_range.current_percentage() - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_set_min_and_max() {
+    let mut attribute = FloatAttribute::new(10.0);
+    attribute.set_max(5.0).expect("Failed to set max");
+    assert_eq!(attribute, 5.0);
+    assert_eq!(
+        attribute.set_max(-1.5),
+        Err(AttributeError::MaxLessThanMinFloat(
+            "-1.5".to_string(),
+            "0".to_string()
+        ))
+    );
+    assert!(attribute.set_max(f32::NAN).is_err());
+    attribute.set_min(1.0).expect("Failed to set min");
+    assert!((attribute.min - 1.0).abs() < f32::EPSILON);
+    assert_eq!(
+        attribute.set_min(6.0),
+        Err(AttributeError::MinGreaterThanMaxFloat(
+            "6".to_string(),
+            "5".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut attribute = FloatAttribute::new_as_defined(0.0, 10.0, 4.0).expect("Failed to create");
+    attribute += 1.5;
+    assert_eq!(attribute, 5.5);
+    attribute -= 0.5;
+    assert_eq!(attribute, 5.0);
+    attribute *= 3.0;
+    assert_eq!(attribute, 10.0);
+    attribute /= 4.0;
+    assert_eq!(attribute, 2.5);
+    assert_eq!(attribute - 5.0, 0.0);
+    assert_eq!(attribute + f32::NAN, 2.5);
+    assert_eq!(attribute / 0.0, 10.0);
+}
+
+#[test]
+fn test_conversions() {
+    let attribute = FloatAttribute::new(3.5);
+    assert!((f32::from(attribute) - 3.5).abs() < f32::EPSILON);
+    assert!((attribute.floor() - 3.0).abs() < f32::EPSILON);
+    assert!(attribute.contains(&3.0));
+    assert_eq!(format!("{attribute}"), "3.5 (1.00%)");
+}
+
+#[test]
+fn test_builder() {
+    let temperature = FloatAttribute::builder()
+        .min(30.0)
+        .max(45.0)
+        .current(50.0)
+        .build()
+        .expect("Failed to build");
+    assert_eq!(temperature, 45.0_f32);
+
+    let cold = FloatAttribute::builder()
+        .max(-10.0)
+        .build()
+        .expect("Failed to build");
+    assert!((cold.min + 10.0).abs() < f32::EPSILON);
+    assert!(FloatAttribute::builder().min(f32::NAN).build().is_err());
+}
@@ -0,0 +1,49 @@
+//! Tests for the `GaugeAttribute` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    gauge_attribute::advance_gauges, FloatAttribute, GaugeAttribute,
+};
+
+#[test]
+fn test_decay() {
+    let mut thirst = GaugeAttribute::new(FloatAttribute::new(10.0), -2.0);
+    assert!(thirst.is_full());
+    assert!((thirst.advance(1.5) + 3.0).abs() < f32::EPSILON);
+    assert!((thirst.current_value() - 7.0).abs() < f32::EPSILON);
+    assert!((thirst.advance(100.0) + 7.0).abs() < f32::EPSILON);
+    assert!(thirst.is_empty());
+}
+
+#[test]
+fn test_growth() {
+    let attribute = FloatAttribute::new_as_defined(30.0, 45.0, 37.0).expect("Failed to create");
+    let mut temperature = GaugeAttribute::new(attribute, 0.25);
+    temperature.advance(4.0);
+    assert!((temperature.current_value() - 38.0).abs() < f32::EPSILON);
+    temperature.advance(1_000.0);
+    assert!(temperature.is_full());
+}
+
+#[test]
+fn test_seconds_until_bound() {
+    let mut hunger = GaugeAttribute::new(FloatAttribute::new(100.0), -0.5);
+    assert_eq!(hunger.seconds_until_bound(), Some(200.0));
+    hunger.rate_per_second = 2.0;
+    assert_eq!(hunger.seconds_until_bound(), Some(0.0));
+    hunger.rate_per_second = 0.0;
+    assert_eq!(hunger.seconds_until_bound(), None);
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let entity = world
+        .spawn(GaugeAttribute::new(FloatAttribute::new(10.0), -1.0))
+        .id();
+
+    world.run_system_once_with(2.5, advance_gauges);
+
+    let gauge = world.get::<GaugeAttribute>(entity).expect("Missing gauge");
+    assert!((gauge.current_value() - 7.5).abs() < f32::EPSILON);
+}
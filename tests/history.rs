@@ -0,0 +1,85 @@
+//! Tests for the `AttributeHistory` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{history::record_history, AttributeHistory, IntegerAttribute};
+
+#[test]
+fn test_samples_at_interval() {
+    let mut history = AttributeHistory::new(1.0, 10);
+    let health = IntegerAttribute::new(100);
+    history.record(&health, 0.5);
+    assert!(history.is_empty());
+    history.record(&health, 0.5);
+    assert_eq!(history.samples(), vec![100]);
+    history.record(&health, 2.0);
+    assert_eq!(history.len(), 3);
+}
+
+#[test]
+fn test_compresses_steady_changes() {
+    let mut history = AttributeHistory::new(1.0, 100);
+    for value in (0..50).rev() {
+        history.push_sample(value);
+    }
+    assert_eq!(history.len(), 50);
+    assert_eq!(history.run_count(), 1);
+    assert_eq!(history.samples(), (0..50).rev().collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_bounded_capacity() {
+    let mut history = AttributeHistory::new(1.0, 3);
+    for value in [10, 20, 20, 5, 7] {
+        history.push_sample(value);
+    }
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.samples(), vec![20, 5, 7]);
+    assert_eq!(history.latest(), Some(7));
+}
+
+#[test]
+fn test_extreme_values() {
+    let mut history = AttributeHistory::new(1.0, 2);
+    for value in [i32::MIN, i32::MAX, i32::MIN] {
+        history.push_sample(value);
+    }
+    assert_eq!(history.samples(), vec![i32::MAX, i32::MIN]);
+}
+
+#[test]
+fn test_last_seconds() {
+    let mut history = AttributeHistory::new(0.25, 100);
+    for value in 0..10 {
+        history.push_sample(value);
+    }
+    assert_eq!(history.last_seconds(1.0), vec![6, 7, 8, 9]);
+    assert_eq!(history.last_seconds(100.0).len(), 10);
+    assert!(history.last_seconds(0.0).is_empty());
+}
+
+#[test]
+fn test_clear() {
+    let mut history = AttributeHistory::new(1.0, 10);
+    history.push_sample(1);
+    history.push_sample(2);
+    history.clear();
+    assert!(history.is_empty());
+    assert!(history.samples().is_empty());
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let entity = world
+        .spawn((IntegerAttribute::new(50), AttributeHistory::new(0.5, 10)))
+        .id();
+    world.run_system_once_with(1.0, record_history::<IntegerAttribute>);
+    assert_eq!(
+        world
+            .entity(entity)
+            .get::<AttributeHistory>()
+            .expect("Missing history")
+            .samples(),
+        vec![50, 50]
+    );
+}
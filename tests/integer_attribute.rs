@@ -1,6 +1,8 @@
+//! Tests for the `IntegerAttribute` struct.
+
 use std::ops::RangeBounds;
 
-use nwest_shared_component_library::{AttributeError, IntegerAttribute};
+use nwest_shared_component_library::{AttributeError, IntegerAttribute, OverflowPolicy};
 
 #[test]
 fn test_with_min_and_max() {
@@ -234,3 +236,165 @@ fn test_range_bounds() {
     assert_eq!(start_bound, std::ops::Bound::Included(&0));
     assert_eq!(end_bound, std::ops::Bound::Included(&100));
 }
+
+#[test]
+fn test_add_with_policy() {
+    let mut attribute = IntegerAttribute::new(100);
+    assert_eq!(
+        attribute.add_with_policy(50, OverflowPolicy::Clamp),
+        Ok(100)
+    );
+    assert_eq!(
+        attribute.add_with_policy(-150, OverflowPolicy::Saturate),
+        Ok(0)
+    );
+    assert_eq!(
+        attribute.add_with_policy(-1, OverflowPolicy::Error),
+        Err(AttributeError::OutOfBounds(-1, 0, 100))
+    );
+    assert_eq!(attribute, 0);
+    assert_eq!(
+        attribute.add_with_policy(-1, OverflowPolicy::WrapAround),
+        Ok(100)
+    );
+    assert_eq!(
+        attribute.add_with_policy(103, OverflowPolicy::WrapAround),
+        Ok(1)
+    );
+}
+
+#[test]
+fn test_try_add() {
+    let mut attribute = IntegerAttribute::new(100);
+    assert_eq!(attribute.try_add(-40), Ok(60));
+    assert_eq!(
+        attribute.try_add(41),
+        Err(AttributeError::OutOfBounds(101, 0, 100))
+    );
+    assert_eq!(attribute, 60);
+
+    let mut attribute =
+        IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, i32::MAX).expect("Failed to create");
+    assert_eq!(
+        attribute.try_add(1),
+        Err(AttributeError::OutOfBounds(
+            i64::from(i32::MAX) + 1,
+            i64::from(i32::MIN),
+            i64::from(i32::MAX)
+        ))
+    );
+}
+
+#[test]
+fn test_saturating_add() {
+    let mut attribute =
+        IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, -10).expect("Failed to create");
+    assert_eq!(attribute.saturating_add(i32::MIN), i32::MIN);
+    assert_eq!(attribute.saturating_add(i32::MAX), -1);
+    attribute.saturating_add(i32::MAX);
+    assert_eq!(attribute.saturating_add(i32::MAX), i32::MAX);
+}
+
+#[test]
+fn test_try_subtract() {
+    let mut attribute = IntegerAttribute::new(10);
+    assert_eq!(attribute.try_subtract(10), Ok(0));
+    assert_eq!(
+        attribute.try_subtract(1),
+        Err(AttributeError::OutOfBounds(-1, 0, 10))
+    );
+    assert_eq!(
+        attribute.try_subtract(i32::MIN),
+        Err(AttributeError::OutOfBounds(-i64::from(i32::MIN), 0, 10))
+    );
+    assert_eq!(attribute, 0);
+}
+
+#[test]
+fn test_try_set_value() {
+    let mut attribute = IntegerAttribute::new(10);
+    assert_eq!(attribute.try_set_value(4), Ok(()));
+    assert_eq!(
+        attribute.try_set_value(11),
+        Err(AttributeError::OutOfBounds(11, 0, 10))
+    );
+    assert_eq!(attribute, 4);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_with_min_max_and_current_shim() {
+    let attribute =
+        IntegerAttribute::with_min_max_and_current(-5, 5, 2).expect("Failed to create attribute");
+    let expected = IntegerAttribute::new_as_defined(-5, 5, 2).expect("Failed to create attribute");
+    assert_eq!(attribute.min, expected.min);
+    assert_eq!(attribute.max, expected.max);
+    assert_eq!(attribute.current, expected.current);
+}
+
+#[test]
+fn test_deref() {
+    let mut attribute = IntegerAttribute::new(10);
+    attribute -= 4;
+    assert_eq!(*attribute, 6);
+    assert_eq!(attribute.pow(2), 36);
+    let value: &i32 = attribute.as_ref();
+    assert_eq!(*value, 6);
+}
+
+static BASE_GOBLIN: IntegerAttribute = IntegerAttribute::new(30);
+
+const WEAKENED_GOBLIN: IntegerAttribute = {
+    let mut attribute = IntegerAttribute {
+        min: -10,
+        max: 30,
+        current: 0,
+    };
+    attribute.set_value(100);
+    attribute
+};
+
+#[test]
+fn test_const_construction() {
+    assert_eq!(BASE_GOBLIN.current_value(), 30);
+    assert_eq!(BASE_GOBLIN.min, 0);
+    assert_eq!(WEAKENED_GOBLIN.current_value(), 30);
+    assert_eq!(WEAKENED_GOBLIN.min, -10);
+    assert_eq!(IntegerAttribute::new(-5).min, -5);
+}
+
+#[test]
+fn test_builder() {
+    let attribute = IntegerAttribute::builder()
+        .min(-10)
+        .max(100)
+        .current(50)
+        .build()
+        .expect("Failed to build");
+    assert_eq!(attribute.min, -10);
+    assert_eq!(attribute.max, 100);
+    assert_eq!(attribute, 50);
+
+    let defaults = IntegerAttribute::builder()
+        .build()
+        .expect("Failed to build");
+    assert_eq!((defaults.min, defaults.max, defaults.current), (0, 0, 0));
+
+    let negative = IntegerAttribute::builder()
+        .max(-5)
+        .build()
+        .expect("Failed to build");
+    assert_eq!(negative.min, IntegerAttribute::new(-5).min);
+
+    let clamped = IntegerAttribute::builder()
+        .max(10)
+        .current(99)
+        .build()
+        .expect("Failed to build");
+    assert_eq!(clamped, 10);
+
+    assert_eq!(
+        IntegerAttribute::builder().min(5).max(1).build(),
+        Err(AttributeError::MinGreaterThanMax(5, 1))
+    );
+}
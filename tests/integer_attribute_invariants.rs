@@ -0,0 +1,227 @@
+//! Exhaustive small-domain tests that no sequence of `IntegerAttribute` operations breaks `min <= current <= max`.
+//!
+//! Every valid attribute built from a handful of bounds, including the extremes of `i32`, is put through every
+//! operation with every interesting operand, and every pair of operations. Overflow panics in test builds, so these
+//! also check that no operation overflows.
+
+use nwest_shared_component_library::{IntegerAttribute, OverflowPolicy};
+
+/// Bounds to build attributes from: a small domain around zero, plus the extremes.
+const BOUNDS: [i32; 7] = [i32::MIN, i32::MIN + 1, -2, 0, 2, i32::MAX - 1, i32::MAX];
+
+/// Operands that cover sign changes, identities, and overflow.
+const OPERANDS: [i32; 9] = [
+    i32::MIN,
+    i32::MIN + 1,
+    -3,
+    -1,
+    0,
+    1,
+    3,
+    i32::MAX - 1,
+    i32::MAX,
+];
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    SetValue(i32),
+    TrySetValue(i32),
+    SetMax(i32),
+    SetMin(i32),
+    AddAssign(i32),
+    Add(i32),
+    SubAssign(i32),
+    Sub(i32),
+    MulAssign(i32),
+    Mul(i32),
+    DivAssign(i32),
+    Div(i32),
+    RemAssign(i32),
+    Rem(i32),
+    Neg,
+    TryAdd(i32),
+    TrySubtract(i32),
+    SaturatingAdd(i32),
+    AddWithPolicy(i32, OverflowPolicy),
+}
+
+impl Operation {
+    // The binary operators are implemented separately from the assignment operators, so both are exercised.
+    #[allow(clippy::assign_op_pattern)]
+    fn apply(self, attribute: &mut IntegerAttribute) {
+        match self {
+            Self::SetValue(value) => attribute.set_value(value),
+            Self::TrySetValue(value) => {
+                let _ = attribute.try_set_value(value);
+            }
+            Self::SetMax(value) => {
+                let _ = attribute.set_max(value);
+            }
+            Self::SetMin(value) => {
+                let _ = attribute.set_min(value);
+            }
+            Self::AddAssign(value) => *attribute += value,
+            Self::Add(value) => *attribute = *attribute + value,
+            Self::SubAssign(value) => *attribute -= value,
+            Self::Sub(value) => *attribute = *attribute - value,
+            Self::MulAssign(value) => *attribute *= value,
+            Self::Mul(value) => *attribute = *attribute * value,
+            Self::DivAssign(value) => *attribute /= value,
+            Self::Div(value) => *attribute = *attribute / value,
+            Self::RemAssign(value) => *attribute %= value,
+            Self::Rem(value) => *attribute = *attribute % value,
+            Self::Neg => *attribute = -*attribute,
+            Self::TryAdd(value) => {
+                let _ = attribute.try_add(value);
+            }
+            Self::TrySubtract(value) => {
+                let _ = attribute.try_subtract(value);
+            }
+            Self::SaturatingAdd(value) => {
+                attribute.saturating_add(value);
+            }
+            Self::AddWithPolicy(value, policy) => {
+                let _ = attribute.add_with_policy(value, policy);
+            }
+        }
+    }
+}
+
+/// Every valid attribute whose min, max, and current are all taken from `BOUNDS`.
+fn attributes() -> Vec<IntegerAttribute> {
+    let mut attributes = Vec::new();
+    for min in BOUNDS {
+        for max in BOUNDS.into_iter().filter(|max| *max >= min) {
+            for current in BOUNDS
+                .into_iter()
+                .filter(|current| (min..=max).contains(current))
+            {
+                attributes.push(IntegerAttribute { max, min, current });
+            }
+        }
+    }
+    attributes
+}
+
+/// Every operation with every operand. Division and remainder by zero panic like integer division, so are skipped.
+fn operations() -> Vec<Operation> {
+    let mut operations = vec![Operation::Neg];
+    for operand in OPERANDS {
+        operations.extend([
+            Operation::SetValue(operand),
+            Operation::TrySetValue(operand),
+            Operation::SetMax(operand),
+            Operation::SetMin(operand),
+            Operation::AddAssign(operand),
+            Operation::Add(operand),
+            Operation::SubAssign(operand),
+            Operation::Sub(operand),
+            Operation::MulAssign(operand),
+            Operation::Mul(operand),
+            Operation::TryAdd(operand),
+            Operation::TrySubtract(operand),
+            Operation::SaturatingAdd(operand),
+            Operation::AddWithPolicy(operand, OverflowPolicy::Clamp),
+            Operation::AddWithPolicy(operand, OverflowPolicy::Saturate),
+            Operation::AddWithPolicy(operand, OverflowPolicy::Error),
+            Operation::AddWithPolicy(operand, OverflowPolicy::WrapAround),
+        ]);
+        if operand != 0 {
+            operations.extend([
+                Operation::DivAssign(operand),
+                Operation::Div(operand),
+                Operation::RemAssign(operand),
+                Operation::Rem(operand),
+            ]);
+        }
+    }
+    operations
+}
+
+fn assert_valid(attribute: &IntegerAttribute, history: &[Operation]) {
+    assert!(
+        attribute.min <= attribute.max,
+        "{attribute:?} has min above max after {history:?}"
+    );
+    assert!(
+        (attribute.min..=attribute.max).contains(&attribute.current),
+        "{attribute:?} is out of range after {history:?}"
+    );
+    assert_eq!(attribute.current_value(), attribute.current);
+    let percentage = attribute.current_percentage();
+    assert!(
+        (0.0..=1.0).contains(&percentage),
+        "{attribute:?} has percentage {percentage} after {history:?}"
+    );
+}
+
+#[test]
+fn test_every_operation_keeps_invariants() {
+    let operations = operations();
+    for start in attributes() {
+        for operation in &operations {
+            let mut attribute = start;
+            operation.apply(&mut attribute);
+            assert_valid(&attribute, &[*operation]);
+        }
+    }
+}
+
+#[test]
+fn test_every_pair_of_operations_keeps_invariants() {
+    let operations = operations();
+    for start in attributes() {
+        for first in &operations {
+            let mut after_first = start;
+            first.apply(&mut after_first);
+            for second in &operations {
+                let mut attribute = after_first;
+                second.apply(&mut attribute);
+                assert_valid(&attribute, &[*first, *second]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_overflow_saturates_towards_the_right_bound() {
+    let mut attribute = IntegerAttribute {
+        max: i32::MAX,
+        min: i32::MIN,
+        current: i32::MIN + 1,
+    };
+    attribute += -5;
+    assert_eq!(attribute, i32::MIN);
+    attribute -= 5;
+    assert_eq!(attribute, i32::MIN);
+    assert_eq!(attribute * 2, i32::MIN);
+    assert_eq!(attribute / -1, i32::MAX);
+    assert_eq!(-attribute, i32::MAX);
+    let mut remainder = attribute;
+    Operation::Rem(-1).apply(&mut remainder);
+    assert_eq!(remainder, 0);
+}
+
+#[test]
+fn test_failed_operations_leave_the_attribute_unchanged() {
+    let operations = [
+        Operation::TrySetValue(i32::MAX),
+        Operation::TryAdd(i32::MAX),
+        Operation::TrySubtract(i32::MAX),
+        Operation::AddWithPolicy(i32::MIN, OverflowPolicy::Error),
+        Operation::SetMax(-3),
+        Operation::SetMin(3),
+    ];
+    let start = IntegerAttribute {
+        max: 2,
+        min: -2,
+        current: 1,
+    };
+    for operation in operations {
+        let mut attribute = start;
+        operation.apply(&mut attribute);
+        assert_eq!(attribute.current, start.current, "{operation:?}");
+        assert_eq!(attribute.min, start.min, "{operation:?}");
+        assert_eq!(attribute.max, start.max, "{operation:?}");
+    }
+}
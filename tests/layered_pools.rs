@@ -0,0 +1,62 @@
+//! Tests for the `LayeredPools` struct.
+
+use nwest_shared_component_library::{IntegerAttribute, LayeredPools, PoolLayer};
+
+fn pools() -> LayeredPools<&'static str> {
+    LayeredPools::new()
+        .with_layer(PoolLayer::new("shield", IntegerAttribute::new(20)).bypassed_by("lightning"))
+        .with_layer(PoolLayer::new("armor", IntegerAttribute::new(30)))
+        .with_layer(PoolLayer::new("health", IntegerAttribute::new(100)))
+}
+
+#[test]
+fn test_drains_in_order() {
+    let mut pools = pools();
+    let breakdown = pools.apply_damage(60, &"physical");
+    assert_eq!(breakdown.absorbed_by("shield"), Some(20));
+    assert_eq!(breakdown.absorbed_by("armor"), Some(30));
+    assert_eq!(breakdown.absorbed_by("health"), Some(10));
+    assert_eq!(breakdown.overflow, 0);
+    assert_eq!(breakdown.total_absorbed(), 60);
+    assert_eq!(pools.total(), 90);
+}
+
+#[test]
+fn test_bypassed_layer_is_untouched() {
+    let mut pools = pools();
+    let breakdown = pools.apply_damage(10, &"lightning");
+    assert!(breakdown.layers[0].bypassed);
+    assert_eq!(breakdown.absorbed_by("shield"), Some(0));
+    assert_eq!(breakdown.absorbed_by("armor"), Some(10));
+    assert_eq!(
+        pools.layer("shield").map(|layer| layer.pool.current),
+        Some(20)
+    );
+}
+
+#[test]
+fn test_overflow() {
+    let mut pools = pools();
+    let breakdown = pools.apply_damage(200, &"physical");
+    assert_eq!(breakdown.overflow, 50);
+    assert_eq!(pools.total(), 0);
+}
+
+#[test]
+fn test_negative_damage_is_ignored() {
+    let mut pools = pools();
+    let breakdown = pools.apply_damage(-10, &"physical");
+    assert_eq!(breakdown.total_absorbed(), 0);
+    assert_eq!(pools.total(), 150);
+}
+
+#[test]
+fn test_layer_respects_min() {
+    let mut pools = LayeredPools::new().with_layer(PoolLayer::new(
+        "health",
+        IntegerAttribute::with_min_and_max(10, 50).expect("Failed to create IntegerAttribute"),
+    ));
+    let breakdown = pools.apply_damage(100, &"physical");
+    assert_eq!(breakdown.absorbed_by("health"), Some(40));
+    assert_eq!(breakdown.overflow, 60);
+}
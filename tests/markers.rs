@@ -0,0 +1,66 @@
+//! Tests for the threshold marker components.
+
+use bevy_ecs::{entity::Entity, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    markers::{
+        update_below_marker, update_threshold_markers, AttributeBelow, AttributeEmpty,
+        AttributeFull,
+    },
+    IntegerAttribute,
+};
+
+fn set(world: &mut World, entity: Entity, value: i32) {
+    world
+        .get_mut::<IntegerAttribute>(entity)
+        .expect("Missing attribute")
+        .set_value(value);
+}
+
+#[test]
+fn test_applies_to() {
+    let mut attribute = IntegerAttribute::new(100);
+    assert!(!AttributeBelow::<25>::applies_to(&attribute));
+    attribute.set_value(24);
+    assert!(AttributeBelow::<25>::applies_to(&attribute));
+    attribute.set_value(25);
+    assert!(!AttributeBelow::<25>::applies_to(&attribute));
+}
+
+#[test]
+fn test_empty_and_full() {
+    let mut world = World::new();
+    let entity = world.spawn(IntegerAttribute::new(10)).id();
+
+    world.run_system_once(update_threshold_markers::<IntegerAttribute>);
+    assert!(world.entity(entity).contains::<AttributeFull>());
+    assert!(!world.entity(entity).contains::<AttributeEmpty>());
+
+    set(&mut world, entity, 0);
+    world.run_system_once(update_threshold_markers::<IntegerAttribute>);
+    assert!(!world.entity(entity).contains::<AttributeFull>());
+    assert!(world.entity(entity).contains::<AttributeEmpty>());
+
+    set(&mut world, entity, 5);
+    world.run_system_once(update_threshold_markers::<IntegerAttribute>);
+    assert!(!world.entity(entity).contains::<AttributeFull>());
+    assert!(!world.entity(entity).contains::<AttributeEmpty>());
+}
+
+#[test]
+fn test_below_marker() {
+    let mut world = World::new();
+    let entity = world.spawn(IntegerAttribute::new(100)).id();
+
+    world.run_system_once(update_below_marker::<25>);
+    assert!(!world.entity(entity).contains::<AttributeBelow<25>>());
+
+    set(&mut world, entity, 10);
+    world.run_system_once(update_below_marker::<25>);
+    world.run_system_once(update_below_marker::<50>);
+    assert!(world.entity(entity).contains::<AttributeBelow<25>>());
+    assert!(world.entity(entity).contains::<AttributeBelow<50>>());
+
+    set(&mut world, entity, 30);
+    world.run_system_once(update_below_marker::<25>);
+    assert!(!world.entity(entity).contains::<AttributeBelow<25>>());
+}
@@ -0,0 +1,118 @@
+//! Tests for offline progression.
+
+use bevy_ecs::world::World;
+use nwest_shared_component_library::{
+    offline::simulate_elapsed, periodic_reset::ResetTarget, Cooldown, FloatAttribute,
+    GaugeAttribute, IntegerAttribute, PeriodicReset, Regeneration, Reputation, ReputationTier,
+    ShieldedAttribute,
+};
+
+const WEEK: f64 = 7.0 * 24.0 * 60.0 * 60.0;
+
+const fn spent(max: i32) -> IntegerAttribute {
+    let mut attribute = IntegerAttribute::new(max);
+    attribute.set_value(0);
+    attribute
+}
+
+#[test]
+fn test_regeneration_keeps_fractions_over_long_durations() {
+    let mut attribute = spent(1_000_000);
+    let mut regeneration = Regeneration::new(0.1, 0.0);
+    assert_eq!(regeneration.simulate_elapsed(&mut attribute, WEEK), 60_480);
+    assert_eq!(attribute, 60_480);
+}
+
+#[test]
+fn test_regeneration_saturates() {
+    let mut attribute = spent(100);
+    let mut regeneration = Regeneration::new(1_000_000.0, 1.0);
+    assert_eq!(regeneration.simulate_elapsed(&mut attribute, WEEK), 100);
+
+    let mut drain = Regeneration::new(-1_000_000.0, 1.0);
+    assert_eq!(drain.simulate_elapsed(&mut attribute, WEEK), -100);
+    assert_eq!(attribute, 0);
+}
+
+#[test]
+fn test_matches_ticking() {
+    let mut ticked = spent(1_000);
+    let mut ticked_regeneration = Regeneration::new(0.75, 0.5);
+    for _ in 0..100 {
+        ticked_regeneration.tick(&mut ticked, 0.25);
+    }
+
+    let mut simulated = spent(1_000);
+    let mut simulated_regeneration = Regeneration::new(0.75, 0.5);
+    simulated_regeneration.simulate_elapsed(&mut simulated, 25.0);
+    assert_eq!(simulated, ticked);
+}
+
+#[test]
+fn test_ignores_invalid_durations() {
+    let mut attribute = spent(10);
+    let mut regeneration = Regeneration::new(1.0, 0.0);
+    assert_eq!(regeneration.simulate_elapsed(&mut attribute, -5.0), 0);
+    assert_eq!(regeneration.simulate_elapsed(&mut attribute, f64::NAN), 0);
+    assert_eq!(attribute, 0);
+
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(10), 10).with_decay(1.0);
+    shielded.add_shield(10);
+    assert_eq!(shielded.simulate_elapsed(-5.0), 0);
+    assert_eq!(shielded.shield_value(), 10);
+}
+
+#[test]
+fn test_components() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(10), 50).with_decay(0.5);
+    shielded.add_shield(50);
+    assert_eq!(shielded.simulate_elapsed(WEEK), 50);
+
+    let mut cooldown = Cooldown::new(60.0).with_charges(5);
+    for _ in 0..5 {
+        cooldown.trigger();
+    }
+    assert_eq!(cooldown.simulate_elapsed(150.0), 2);
+    assert!((cooldown.remaining() - 30.0).abs() < f32::EPSILON);
+    assert_eq!(cooldown.simulate_elapsed(WEEK), 3);
+
+    let mut hunger = GaugeAttribute::new(FloatAttribute::new(100.0), -0.001);
+    hunger.simulate_elapsed(WEEK);
+    assert!(hunger.is_empty());
+
+    let mut reputation = Reputation::default().with_decay(0, 0.01);
+    reputation.set_standing("guards", 3000);
+    reputation.simulate_elapsed(WEEK);
+    assert_eq!(reputation.standing("guards"), 0);
+    assert_eq!(reputation.tier("guards"), ReputationTier::Neutral);
+}
+
+#[test]
+fn test_world() {
+    let mut world = World::new();
+    let regenerating = world.spawn((spent(100), Regeneration::new(1.0, 10.0))).id();
+    let daily = world
+        .spawn((spent(5), PeriodicReset::on_day_tick(ResetTarget::Max)))
+        .id();
+    let hourly = world
+        .spawn((spent(5), PeriodicReset::every(3600.0, ResetTarget::Max)))
+        .id();
+    let mut cooldown = Cooldown::new(10.0);
+    cooldown.trigger();
+    let recharging = world.spawn(cooldown).id();
+
+    simulate_elapsed(&mut world, 3600.0);
+
+    let current = |entity| {
+        world
+            .get::<IntegerAttribute>(entity)
+            .map(|attribute| attribute.current)
+    };
+    assert_eq!(current(regenerating), Some(100));
+    assert_eq!(current(daily), Some(0));
+    assert_eq!(current(hourly), Some(5));
+    assert_eq!(
+        world.get::<Cooldown>(recharging).map(Cooldown::is_ready),
+        Some(true)
+    );
+}
@@ -0,0 +1,123 @@
+//! Tests for the `OperationQueue` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    operation_queue::apply_operation_queues, AttributeOperation, IntegerAttribute, OperationQueue,
+};
+
+const fn wounded() -> IntegerAttribute {
+    let mut attribute = IntegerAttribute::new(100);
+    attribute.set_value(20);
+    attribute
+}
+
+#[test]
+fn test_adds_are_folded() {
+    let mut queue = OperationQueue::new();
+    queue.add(10);
+    queue.add(-5);
+    queue.add(30);
+    assert_eq!(queue.resolve(&wounded()), 55);
+}
+
+#[test]
+fn test_adds_clamp_once() {
+    let mut queue = OperationQueue::new();
+    queue.add(200);
+    queue.add(-150);
+    assert_eq!(queue.resolve(&wounded()), 70);
+    queue.add(i32::MAX);
+    queue.add(i32::MAX);
+    assert_eq!(queue.resolve(&wounded()), 100);
+}
+
+#[test]
+fn test_set_then_adds() {
+    let mut queue = OperationQueue::new();
+    queue.set(50);
+    queue.add(10);
+    assert_eq!(queue.resolve(&wounded()), 60);
+}
+
+#[test]
+fn test_equal_priority_sets_prefer_larger() {
+    let mut queue = OperationQueue::new();
+    queue.set(50);
+    queue.set_to_max();
+    queue.set(70);
+    assert_eq!(queue.resolve(&wounded()), 100);
+}
+
+#[test]
+fn test_priority_wins() {
+    let mut queue = OperationQueue::new();
+    queue.set_to_max();
+    queue.push(AttributeOperation::SetToMin, 5);
+    assert_eq!(queue.resolve(&wounded()), 0);
+}
+
+#[test]
+fn test_lower_priority_adds_are_overridden() {
+    let mut queue = OperationQueue::new();
+    queue.push(AttributeOperation::Add(-10), 0);
+    queue.push(AttributeOperation::Set(40), 1);
+    queue.push(AttributeOperation::Add(5), 1);
+    queue.push(AttributeOperation::Add(3), 2);
+    assert_eq!(queue.resolve(&wounded()), 48);
+}
+
+#[test]
+fn test_order_independent() {
+    let operations = [
+        (AttributeOperation::Set(50), 0),
+        (AttributeOperation::Add(10), 0),
+        (AttributeOperation::SetToMax, 0),
+        (AttributeOperation::Add(-25), 0),
+    ];
+    let mut forward = OperationQueue::new();
+    let mut backward = OperationQueue::new();
+    for (operation, priority) in operations {
+        forward.push(operation, priority);
+    }
+    for (operation, priority) in operations.into_iter().rev() {
+        backward.push(operation, priority);
+    }
+    assert_eq!(forward.resolve(&wounded()), 85);
+    assert_eq!(backward.resolve(&wounded()), 85);
+}
+
+#[test]
+fn test_apply_drains() {
+    let mut attribute = wounded();
+    let mut queue = OperationQueue::new();
+    assert!(!queue.apply(&mut attribute));
+    queue.set(20);
+    assert!(!queue.apply(&mut attribute));
+    queue.add(1);
+    assert!(queue.apply(&mut attribute));
+    assert_eq!(attribute, 21);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let mut queue = OperationQueue::new();
+    queue.set_to_max();
+    queue.add(-30);
+    let entity = world.spawn((wounded(), queue)).id();
+
+    world.run_system_once(apply_operation_queues::<IntegerAttribute>);
+
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(entity)
+            .expect("Missing attribute")
+            .current_value(),
+        70
+    );
+    assert!(world
+        .get::<OperationQueue>(entity)
+        .expect("Missing queue")
+        .is_empty());
+}
@@ -0,0 +1,62 @@
+//! Tests for the `Percent` type.
+
+use nwest_shared_component_library::{AttributeError, Percent};
+
+#[test]
+fn test_new() {
+    assert_eq!(Percent::new(0.5).map(|p| p.fraction()), Ok(0.5));
+    assert_eq!(Percent::new(0.0), Ok(Percent::ZERO));
+    assert_eq!(Percent::new(1.0), Ok(Percent::FULL));
+    assert_eq!(
+        Percent::new(1.01),
+        Err(AttributeError::ConversionError(
+            "Percent 1.01 is not between 0.0 and 1.0.".to_string()
+        ))
+    );
+    assert!(Percent::new(-0.1).is_err());
+    assert!(Percent::new(f64::NAN).is_err());
+}
+
+#[test]
+fn test_from_percentage() {
+    let percent = Percent::from_percentage(25.0).expect("Invalid percent");
+    assert!((percent.fraction() - 0.25).abs() < f64::EPSILON);
+    assert!(Percent::from_percentage(101.0).is_err());
+}
+
+#[test]
+fn test_clamped() {
+    assert_eq!(Percent::clamped(2.0), Percent::FULL);
+    assert_eq!(Percent::clamped(-2.0), Percent::ZERO);
+    assert_eq!(Percent::clamped(f64::NAN), Percent::ZERO);
+}
+
+#[test]
+fn test_of() {
+    let percent = Percent::new(0.25).expect("Invalid percent");
+    assert_eq!(percent.of(100), 25);
+    assert_eq!(percent.of(10), 3);
+    assert_eq!(percent.of(-10), -3);
+    assert_eq!(Percent::FULL.of(i32::MAX), i32::MAX);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Percent::FULL.to_string(), "100%");
+    assert_eq!(Percent::ZERO.to_string(), "0%");
+    assert_eq!(
+        Percent::new(0.3333).expect("Invalid percent").to_string(),
+        "33.33%"
+    );
+}
+
+#[test]
+fn test_serde_validates() {
+    let percent: Percent = serde_json::from_str("0.75").expect("Failed to deserialize");
+    assert_eq!(percent.to_string(), "75%");
+    assert_eq!(
+        serde_json::to_string(&percent).expect("Failed to serialize"),
+        "0.75"
+    );
+    assert!(serde_json::from_str::<Percent>("1.5").is_err());
+}
@@ -0,0 +1,136 @@
+//! Tests for the `PeriodicReset` component.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    periodic_reset::{apply_day_ticks, tick_periodic_resets, ResetSchedule, ResetTarget},
+    DayTick, IntegerAttribute, PeriodicReset,
+};
+
+const fn spent(max: i32) -> IntegerAttribute {
+    let mut attribute = IntegerAttribute::new(max);
+    attribute.set_value(0);
+    attribute
+}
+
+#[test]
+fn test_tick_counts_due_resets() {
+    let mut reset = PeriodicReset::every(10.0, ResetTarget::Max);
+    assert_eq!(reset.tick(9.5), 0);
+    assert_eq!(reset.tick(0.5), 1);
+    assert!(reset.elapsed().abs() < f64::EPSILON);
+    assert_eq!(reset.tick(35.0), 3);
+    assert_eq!(reset.time_until_reset(), Some(5.0));
+}
+
+#[test]
+fn test_small_deltas_over_a_long_interval() {
+    let mut reset = PeriodicReset::every(86_400.0, ResetTarget::Max);
+    let mut resets = 0;
+    for _ in 0..(86_400 * 60) {
+        resets += reset.tick(1.0 / 60.0);
+    }
+    assert_eq!(resets, 1);
+    assert!(reset.elapsed() < 1.0);
+}
+
+#[test]
+fn test_ignores_invalid_input() {
+    let mut reset = PeriodicReset::every(10.0, ResetTarget::Max);
+    assert_eq!(reset.tick(-5.0), 0);
+    assert_eq!(reset.tick(f64::NAN), 0);
+    assert_eq!(reset.tick(f64::INFINITY), 0);
+    assert!(reset.elapsed().abs() < f64::EPSILON);
+
+    let mut never = PeriodicReset::every(0.0, ResetTarget::Max);
+    assert_eq!(never.tick(100.0), 0);
+    assert_eq!(never.time_until_reset(), None);
+
+    let mut daily = PeriodicReset::on_day_tick(ResetTarget::Max);
+    assert_eq!(daily.tick(100.0), 0);
+    assert_eq!(daily.time_until_reset(), None);
+}
+
+#[test]
+fn test_apply_to() {
+    let mut attribute = spent(10);
+    PeriodicReset::every(1.0, ResetTarget::Max).apply_to(&mut attribute);
+    assert_eq!(attribute, 10);
+    PeriodicReset::every(1.0, ResetTarget::Value(3)).apply_to(&mut attribute);
+    assert_eq!(attribute, 3);
+    PeriodicReset::every(1.0, ResetTarget::Value(50)).apply_to(&mut attribute);
+    assert_eq!(attribute, 10);
+}
+
+#[test]
+fn test_offline_time_survives_serialization() {
+    let mut reset = PeriodicReset::every(60.0, ResetTarget::Max);
+    reset.tick(45.0);
+    let json = serde_json::to_string(&reset).expect("Failed to serialize");
+    let mut loaded: PeriodicReset = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(loaded, reset);
+    assert_eq!(loaded.schedule, ResetSchedule::Interval(60.0));
+    assert_eq!(loaded.tick(15.0), 1);
+}
+
+#[test]
+fn test_interval_system() {
+    let mut world = World::new();
+    let entity = world
+        .spawn((spent(5), PeriodicReset::every(2.0, ResetTarget::Max)))
+        .id();
+    let daily = world
+        .spawn((spent(5), PeriodicReset::on_day_tick(ResetTarget::Max)))
+        .id();
+
+    world.run_system_once_with(1.0, tick_periodic_resets::<IntegerAttribute>);
+    assert_eq!(world.get::<IntegerAttribute>(entity), Some(&spent(5)));
+    world.run_system_once_with(1.0, tick_periodic_resets::<IntegerAttribute>);
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(entity)
+            .map(|attribute| attribute.current),
+        Some(5)
+    );
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(daily)
+            .map(|attribute| attribute.current),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_day_tick_system() {
+    let mut world = World::new();
+    world.init_resource::<Events<DayTick>>();
+    let daily = world
+        .spawn((spent(5), PeriodicReset::on_day_tick(ResetTarget::Value(2))))
+        .id();
+    let interval = world
+        .spawn((spent(5), PeriodicReset::every(2.0, ResetTarget::Max)))
+        .id();
+
+    world.run_system_once(apply_day_ticks::<IntegerAttribute>);
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(daily)
+            .map(|attribute| attribute.current),
+        Some(0)
+    );
+
+    world.send_event(DayTick);
+    world.send_event(DayTick);
+    world.run_system_once(apply_day_ticks::<IntegerAttribute>);
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(daily)
+            .map(|attribute| attribute.current),
+        Some(2)
+    );
+    assert_eq!(
+        world
+            .get::<IntegerAttribute>(interval)
+            .map(|attribute| attribute.current),
+        Some(0)
+    );
+}
@@ -0,0 +1,109 @@
+//! Tests for the `AttributesPlugin`.
+#![cfg(feature = "bevy_app")]
+
+use std::time::Duration;
+
+use bevy_app::App;
+use bevy_ecs::event::Events;
+use bevy_time::Time;
+use nwest_shared_component_library::{
+    plugin::AttributesPlugin, AttributeChangeTracker, AttributeChanged, DownedEvent, DownedState,
+    DownedTransition, IntegerAttribute, Regeneration, ReviveRequest,
+};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>().add_plugins(AttributesPlugin);
+    app
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+#[test]
+fn test_registers_events() {
+    let app = app();
+    assert!(app.world().contains_resource::<Events<DownedEvent>>());
+    assert!(app.world().contains_resource::<Events<ReviveRequest>>());
+}
+
+#[test]
+fn test_regeneration_runs() {
+    let mut app = app();
+    let mut health = IntegerAttribute::new(100);
+    health.set_value(50);
+    let entity = app
+        .world_mut()
+        .spawn((health, Regeneration::new(10.0, 1.0)))
+        .id();
+
+    advance(&mut app, 1.0);
+
+    assert_eq!(
+        *app.world()
+            .entity(entity)
+            .get::<IntegerAttribute>()
+            .expect("Missing attribute"),
+        60
+    );
+}
+
+#[test]
+fn test_downed_state_runs() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((IntegerAttribute::new(100) - 100, DownedState::new(1.0, 10)))
+        .id();
+
+    advance(&mut app, 0.5);
+    advance(&mut app, 1.0);
+
+    let transitions: Vec<DownedTransition> = app
+        .world_mut()
+        .resource_mut::<Events<DownedEvent>>()
+        .drain()
+        .filter(|event| event.entity == entity)
+        .map(|event| event.transition)
+        .collect();
+    assert_eq!(
+        transitions,
+        vec![DownedTransition::Downed, DownedTransition::Died]
+    );
+}
+
+#[test]
+fn test_attribute_changes_sent_after_regeneration() {
+    let mut app = app();
+    let mut health = IntegerAttribute::new(100);
+    health.set_value(50);
+    let entity = app
+        .world_mut()
+        .spawn((
+            health,
+            Regeneration::new(10.0, 1.0),
+            AttributeChangeTracker::new(),
+        ))
+        .id();
+
+    advance(&mut app, 0.5);
+    advance(&mut app, 0.5);
+
+    let changes: Vec<AttributeChanged> = app
+        .world_mut()
+        .resource_mut::<Events<AttributeChanged>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        changes,
+        vec![AttributeChanged {
+            entity,
+            old: 50,
+            new: 60
+        }]
+    );
+}
@@ -0,0 +1,76 @@
+//! Tests for the `prelude` module.
+
+use nwest_shared_component_library::prelude::*;
+
+#[test]
+fn test_prelude_covers_common_use() {
+    let mut health = IntegerAttribute::new(100);
+    assert_eq!(
+        health.try_subtract(150),
+        Err(AttributeError::OutOfBounds(-50, 0, 100))
+    );
+
+    let decoded = IntegerAttribute::from_bytes(&health.to_bytes()).expect("Failed to decode");
+    assert_eq!(decoded, health);
+
+    let mut gold = FixedDecimalAttribute::<3>::new(1_000);
+    gold -= 1;
+    assert_eq!(gold, 999);
+}
+
+#[test]
+fn test_prelude_covers_extensions() {
+    let mut health = IntegerAttribute::new(100);
+    let changes = health
+        .apply_ui_edit(AttributeField::Max, 50, EditPolicy::Adjust)
+        .expect("Failed to edit");
+    assert_eq!(changes.len(), 2);
+    assert!((health.editor_hint().max - 50.0).abs() < f64::EPSILON);
+
+    let mut mana = IntegerAttribute::new(100);
+    mana.set_value(0);
+    StatTransaction::new()
+        .spend("health", &mut health, 10)
+        .commit()
+        .expect("Failed to commit");
+    let moved =
+        transfer(&mut health, &mut mana, 20, TransferPolicy::Exact).expect("Failed to transfer");
+    assert_eq!(moved.received, 20);
+    let rate = ExchangeRate::new(2, 1).expect("Invalid rate");
+    convert_between(&mut health, &mut mana, rate, 5, TransferPolicy::Clamp)
+        .expect("Failed to convert");
+    assert_eq!((health.current_value(), mana.current_value()), (15, 30));
+
+    let mut buffer = RollbackBuffer::new(4);
+    buffer.record(1, &health);
+    health -= 15;
+    assert!(buffer.rollback(1, &mut health));
+    assert_eq!(health, 15);
+
+    let mut total = Accumulator::new();
+    total.add(30);
+    let mut achievements =
+        Achievements::new().with("thirty", AchievementCondition::TotalAtLeast(30));
+    let context = AchievementContext {
+        accumulator: Some(&total),
+        ..Default::default()
+    };
+    assert_eq!(achievements.evaluate(&context), vec!["thirty".to_string()]);
+
+    let mut sum = KahanAccumulator::new();
+    sum.add(0.5);
+    assert!((sum.total() - 0.5).abs() < f64::EPSILON);
+
+    let curve = ScalingCurve::Linear {
+        base: 10.0,
+        per_level: 5.0,
+    };
+    assert_eq!(curve.evaluate_rounded(3), 20);
+}
+
+#[cfg(feature = "dice")]
+#[test]
+fn test_prelude_covers_dice() {
+    let roll: Roll = "2d6+1".parse().expect("Invalid roll");
+    assert_eq!((roll.min(), roll.max()), (3, 13));
+}
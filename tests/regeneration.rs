@@ -0,0 +1,71 @@
+//! Tests for the `Regeneration` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{regeneration::regenerate, IntegerAttribute, Regeneration};
+
+const fn wounded() -> IntegerAttribute {
+    let mut attribute = IntegerAttribute::new(100);
+    attribute.set_value(10);
+    attribute
+}
+
+#[test]
+fn test_applies_per_interval() {
+    let mut health = wounded();
+    let mut regeneration = Regeneration::new(5.0, 2.0);
+    assert_eq!(regeneration.tick(&mut health, 1.0), 0);
+    assert_eq!(regeneration.tick(&mut health, 1.0), 10);
+    assert_eq!(health, 20);
+}
+
+#[test]
+fn test_multiple_intervals_in_one_tick() {
+    let mut health = wounded();
+    let mut regeneration = Regeneration::new(1.0, 1.0);
+    assert_eq!(regeneration.tick(&mut health, 3.5), 3);
+    assert_eq!(regeneration.tick(&mut health, 0.5), 1);
+    assert_eq!(health, 14);
+}
+
+#[test]
+fn test_carries_fractions() {
+    let mut health = wounded();
+    let mut regeneration = Regeneration::new(0.5, 1.0);
+    regeneration.tick(&mut health, 1.0);
+    assert_eq!(health, 10);
+    regeneration.tick(&mut health, 1.0);
+    assert_eq!(health, 11);
+}
+
+#[test]
+fn test_zero_interval_applies_every_tick() {
+    let mut health = wounded();
+    let mut regeneration = Regeneration::new(10.0, 0.0);
+    assert_eq!(regeneration.tick(&mut health, 0.5), 5);
+    assert_eq!(health, 15);
+}
+
+#[test]
+fn test_clamps_to_max_and_min() {
+    let mut health = IntegerAttribute::new(100);
+    let mut regeneration = Regeneration::new(10.0, 1.0);
+    assert_eq!(regeneration.tick(&mut health, 1.0), 0);
+
+    let mut poison = Regeneration::new(-50.0, 1.0);
+    assert_eq!(poison.tick(&mut health, 3.0), -100);
+    assert_eq!(health, 0);
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let entity = world.spawn((wounded(), Regeneration::new(4.0, 0.5))).id();
+    world.run_system_once_with(1.0, regenerate::<IntegerAttribute>);
+    assert_eq!(
+        *world
+            .entity(entity)
+            .get::<IntegerAttribute>()
+            .expect("Missing attribute"),
+        14
+    );
+}
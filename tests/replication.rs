@@ -0,0 +1,110 @@
+//! Tests for `Replicate` and `ReplicationTracker`.
+
+use nwest_shared_component_library::{
+    replication::{Replicate, ReplicationTracker},
+    wire::WireFormat,
+    AttributeError, FixedDecimalAttribute, FloatAttribute, IntegerAttribute,
+};
+
+#[test]
+fn test_full_delta_without_baseline() {
+    let health = IntegerAttribute::new_as_defined(-10, 50, 20).expect("Failed to create");
+    let mut delta = Vec::new();
+    health.write_delta(None, &mut delta);
+    assert_eq!(delta[0], 0b111);
+    assert_eq!(delta.len(), 1 + IntegerAttribute::WIRE_SIZE);
+
+    let mut received = IntegerAttribute::default();
+    assert_eq!(received.apply_delta(&delta), Ok(delta.len()));
+    assert_eq!(received.min, -10);
+    assert_eq!(received.max, 50);
+    assert_eq!(received, 20);
+}
+
+#[test]
+fn test_only_changed_fields() {
+    let before = FixedDecimalAttribute::<3>::new(10_000);
+    let mut after = before;
+    after.set_max(20_000).expect("Failed to set max");
+
+    let mut delta = Vec::new();
+    after.write_delta(Some(&before), &mut delta);
+    assert_eq!(delta[0], 0b010);
+    assert_eq!(delta.len(), 9);
+
+    let mut received = before;
+    received.apply_delta(&delta).expect("Failed to apply");
+    assert_eq!(received.max, 20_000);
+    assert_eq!(received, 10_000);
+}
+
+#[test]
+fn test_unchanged_is_one_byte() {
+    let gauge = FloatAttribute::new(1.5);
+    let mut delta = Vec::new();
+    gauge.write_delta(Some(&gauge), &mut delta);
+    assert_eq!(delta, vec![0]);
+}
+
+#[test]
+fn test_invalid_deltas_change_nothing() {
+    let mut health = IntegerAttribute::new(100);
+    assert!(health.apply_delta(&[]).is_err());
+    assert!(health.apply_delta(&[0b1000]).is_err());
+    assert!(health.apply_delta(&[0b100, 1, 2]).is_err());
+
+    // A min above the current max is rejected.
+    let mut delta = vec![0b001];
+    delta.extend_from_slice(&500_i32.to_le_bytes());
+    assert_eq!(
+        health.apply_delta(&delta),
+        Err(AttributeError::MinGreaterThanMax(500, 100))
+    );
+    assert_eq!(health.min, 0);
+    assert_eq!(health, 100);
+}
+
+#[test]
+fn test_consecutive_deltas_in_one_buffer() {
+    let first = IntegerAttribute::new(10);
+    let second = FloatAttribute::new(2.0);
+    let mut buffer = Vec::new();
+    first.write_delta(None, &mut buffer);
+    second.write_delta(None, &mut buffer);
+
+    let mut received_first = IntegerAttribute::default();
+    let mut received_second = FloatAttribute::default();
+    let read = received_first
+        .apply_delta(&buffer)
+        .expect("Failed to apply");
+    received_second
+        .apply_delta(&buffer[read..])
+        .expect("Failed to apply");
+    assert_eq!(received_first, 10);
+    assert_eq!(received_second, 2.0_f32);
+}
+
+#[test]
+fn test_tracker() {
+    let mut mana = IntegerAttribute::new(50);
+    let mut tracker = ReplicationTracker::new();
+    let mut received = IntegerAttribute::default();
+
+    received
+        .apply_delta(&tracker.delta(&mana).expect("Missing first delta"))
+        .expect("Failed to apply");
+    assert_eq!(tracker.delta(&mana), None);
+
+    mana -= 20;
+    received
+        .apply_delta(&tracker.delta(&mana).expect("Missing delta"))
+        .expect("Failed to apply");
+    assert_eq!(received, 30);
+    assert_eq!(
+        tracker.last_sent().map(IntegerAttribute::current_value),
+        Some(30)
+    );
+
+    tracker.reset();
+    assert_eq!(tracker.delta(&mana).map(|delta| delta.len()), Some(13));
+}
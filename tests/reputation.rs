@@ -0,0 +1,116 @@
+//! Tests for the `Reputation` component.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    reputation::{
+        decay_reputation, emit_reputation_changes, Reputation, ReputationThresholds,
+        ReputationTier, ReputationTierChanged, TierChange,
+    },
+    IntegerAttribute,
+};
+
+#[test]
+fn test_thresholds() {
+    let thresholds = ReputationThresholds::default();
+    assert_eq!(thresholds.tier(-500), ReputationTier::Hostile);
+    assert_eq!(thresholds.tier(-499), ReputationTier::Neutral);
+    assert_eq!(thresholds.tier(500), ReputationTier::Friendly);
+    assert_eq!(thresholds.tier(2500), ReputationTier::Exalted);
+}
+
+#[test]
+fn test_unseen_faction() {
+    let reputation = Reputation::default();
+    assert_eq!(reputation.standing("bandits"), 0);
+    assert_eq!(reputation.tier("bandits"), ReputationTier::Neutral);
+    assert_eq!(reputation.standings().count(), 0);
+}
+
+#[test]
+fn test_change_is_clamped() {
+    let mut reputation = Reputation::default();
+    reputation.change("guards", 10_000);
+    assert_eq!(reputation.standing("guards"), 3000);
+    reputation.change("guards", i32::MIN);
+    assert_eq!(reputation.standing("guards"), -3000);
+}
+
+#[test]
+fn test_tier_changes() {
+    let mut reputation = Reputation::default();
+    assert_eq!(reputation.change("guards", 100), None);
+    assert_eq!(
+        reputation.change("guards", -1000),
+        Some(TierChange {
+            faction: "guards".to_string(),
+            old: ReputationTier::Neutral,
+            new: ReputationTier::Hostile,
+        })
+    );
+    assert_eq!(reputation.drain_tier_changes().len(), 1);
+    assert!(reputation.drain_tier_changes().is_empty());
+}
+
+#[test]
+fn test_custom_range() {
+    let initial = IntegerAttribute::new_as_defined(0, 100, 50).expect("Failed to create attribute");
+    let thresholds = ReputationThresholds {
+        neutral: 25,
+        friendly: 60,
+        exalted: 90,
+    };
+    let mut reputation = Reputation::new(initial, thresholds);
+    assert_eq!(reputation.tier("elves"), ReputationTier::Neutral);
+    reputation.change("elves", -30);
+    assert_eq!(reputation.tier("elves"), ReputationTier::Hostile);
+}
+
+#[test]
+fn test_decay() {
+    let mut reputation = Reputation::default().with_decay(0, 10.0);
+    reputation.set_standing("guards", 505);
+    reputation.set_standing("bandits", -15);
+    reputation.drain_tier_changes();
+
+    let changes = reputation.decay(1.0);
+    assert_eq!(reputation.standing("guards"), 495);
+    assert_eq!(reputation.standing("bandits"), -5);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].new, ReputationTier::Neutral);
+
+    reputation.decay(100.0);
+    assert_eq!(reputation.standing("guards"), 0);
+    assert_eq!(reputation.standing("bandits"), 0);
+}
+
+#[test]
+fn test_decay_carries_fractions() {
+    let mut reputation = Reputation::default().with_decay(0, 0.5);
+    reputation.set_standing("guards", 10);
+    reputation.decay(1.0);
+    assert_eq!(reputation.standing("guards"), 10);
+    reputation.decay(1.0);
+    assert_eq!(reputation.standing("guards"), 9);
+}
+
+#[test]
+fn test_systems() {
+    let mut world = World::new();
+    world.init_resource::<Events<ReputationTierChanged>>();
+    let mut reputation = Reputation::default().with_decay(0, 1000.0);
+    reputation.set_standing("guards", 2600);
+    reputation.drain_tier_changes();
+    let entity = world.spawn(reputation).id();
+
+    world.run_system_once_with(1.0, decay_reputation);
+    world.run_system_once(emit_reputation_changes);
+
+    let events: Vec<ReputationTierChanged> = world
+        .resource_mut::<Events<ReputationTierChanged>>()
+        .drain()
+        .collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, entity);
+    assert_eq!(events[0].change.old, ReputationTier::Exalted);
+    assert_eq!(events[0].change.new, ReputationTier::Friendly);
+}
@@ -0,0 +1,114 @@
+//! Tests for `Snapshot` and `RollbackBuffer`.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    rollback::{record_rollback, RollbackBuffer, Snapshot},
+    Cooldown, FixedDecimalAttribute, IntegerAttribute, ShieldedAttribute,
+};
+
+#[test]
+fn test_snapshot_restores_limits() {
+    let mut gold = FixedDecimalAttribute::<2>::new(1_000);
+    let saved = gold.snapshot();
+    gold.set_min(-500).expect("Failed to set min");
+    gold -= 1_200;
+    gold.restore(&saved);
+    assert_eq!(gold.min, 0);
+    assert_eq!(gold, 1_000);
+}
+
+#[test]
+fn test_snapshot_restores_private_state() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    shielded.add_shield(30);
+    let saved = shielded.snapshot();
+    shielded.damage(60);
+    shielded.restore(&saved);
+    assert_eq!(shielded.shield_value(), 30);
+    assert_eq!(shielded.current_value(), 100);
+}
+
+#[test]
+fn test_ring_drops_oldest() {
+    let mut buffer = RollbackBuffer::<IntegerAttribute>::new(3);
+    let mut health = IntegerAttribute::new(100);
+    for frame in 10..15 {
+        buffer.record(frame, &health);
+        health -= 1;
+    }
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.oldest_frame(), Some(12));
+    assert_eq!(buffer.latest_frame(), Some(14));
+    assert!(buffer.get(11).is_none());
+    assert_eq!(
+        buffer.get(13).map(IntegerAttribute::current_value),
+        Some(97)
+    );
+}
+
+#[test]
+fn test_rollback_and_resimulate() {
+    let mut buffer = RollbackBuffer::<IntegerAttribute>::new(8);
+    let mut health = IntegerAttribute::new(100);
+    for frame in 0..4 {
+        buffer.record(frame, &health);
+        health -= 10;
+    }
+
+    assert!(buffer.rollback(1, &mut health));
+    assert_eq!(health, 90);
+    assert_eq!(buffer.latest_frame(), Some(1));
+
+    // Re-simulating frame 1 replaces it.
+    health -= 5;
+    buffer.record(1, &health);
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.get(1).map(IntegerAttribute::current_value), Some(85));
+}
+
+#[test]
+fn test_rollback_to_missing_frame() {
+    let mut buffer = RollbackBuffer::<IntegerAttribute>::new(0);
+    assert_eq!(buffer.capacity(), 1);
+    let mut health = IntegerAttribute::new(100);
+    buffer.record(5, &health);
+    health -= 50;
+    assert!(!buffer.rollback(4, &mut health));
+    assert_eq!(health, 50);
+    buffer.clear();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let mut buffer = RollbackBuffer::<IntegerAttribute>::new(4);
+    buffer.record(1, &IntegerAttribute::new(10));
+    buffer.record(2, &IntegerAttribute::new(20));
+    let json = serde_json::to_string(&buffer).expect("Failed to serialize");
+    let decoded: RollbackBuffer<IntegerAttribute> =
+        serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded.get(2).map(|state| state.max), Some(20));
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let entity = world
+        .spawn((Cooldown::new(5.0), RollbackBuffer::<Cooldown>::new(4)))
+        .id();
+
+    world.run_system_once_with(7, record_rollback::<Cooldown>);
+    world
+        .get_mut::<Cooldown>(entity)
+        .expect("Missing cooldown")
+        .trigger();
+    world.run_system_once_with(8, record_rollback::<Cooldown>);
+
+    let buffer = world
+        .get::<RollbackBuffer<Cooldown>>(entity)
+        .expect("Missing buffer");
+    assert_eq!(buffer.len(), 2);
+    assert!(buffer.get(7).is_some_and(Cooldown::is_ready));
+    assert!(!buffer.get(8).is_some_and(Cooldown::is_ready));
+}
@@ -0,0 +1,79 @@
+//! Tests for JSON Schema generation.
+#![cfg(feature = "schema")]
+
+use nwest_shared_component_library::schema::json_schema_for_all;
+
+#[test]
+fn test_covers_all_types() {
+    let schema = json_schema_for_all();
+    for name in [
+        "IntegerAttribute",
+        "FixedDecimalAttribute_for_3",
+        "FloatAttribute",
+        "LayeredPools_for_String",
+        "DamageBreakdown",
+        "DownedState",
+        "LifeState",
+        "Regeneration",
+        "Level",
+        "LinearCurve",
+        "ExponentialCurve",
+        "TableCurve",
+        "AttributeHistory",
+        "Wallet",
+        "Reputation",
+        "ShieldedAttribute",
+        "BoundedAttribute",
+        "OverflowPolicy",
+        "Percent",
+        "Cooldown",
+        "GaugeAttribute",
+        "Health",
+        "Mana",
+        "Stamina",
+        "Accumulator",
+        "KahanAccumulator",
+        "OperationQueue",
+        "AttributeOperation",
+        "ScalingCurve",
+        "CurvePoint",
+        "DisplayedAttribute",
+        "Easing",
+        "TransferPolicy",
+        "Watermarks",
+        "Achievements",
+        "AchievementCondition",
+        "PeriodicReset",
+        "ResetSchedule",
+        "ResetTarget",
+        "AttributeField",
+        "EditPolicy",
+        "EditorHint",
+        "Depletable",
+        "DepletionPolicy",
+    ] {
+        assert!(
+            schema.definitions.contains_key(name),
+            "Missing schema for {name}"
+        );
+    }
+}
+
+#[cfg(feature = "dice")]
+#[test]
+fn test_covers_dice() {
+    assert!(json_schema_for_all().definitions.contains_key("Roll"));
+}
+
+#[test]
+fn test_integer_attribute_fields() {
+    let schema = json_schema_for_all();
+    let attribute = schema.definitions["IntegerAttribute"].clone().into_object();
+    let properties = &attribute
+        .object
+        .expect("IntegerAttribute should be an object")
+        .properties;
+    for field in ["min", "max", "current"] {
+        assert!(properties.contains_key(field), "Missing field {field}");
+    }
+}
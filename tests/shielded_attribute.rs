@@ -0,0 +1,131 @@
+//! Tests for the `ShieldedAttribute` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    shielded_attribute::{decay_shields, ShieldedDamage},
+    IntegerAttribute, ShieldedAttribute,
+};
+
+#[test]
+fn test_new() {
+    let shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    assert_eq!(shielded.current_value(), 100);
+    assert_eq!(shielded.shield_value(), 0);
+    assert_eq!(shielded.shield_max(), 50);
+    assert_eq!(
+        ShieldedAttribute::new(IntegerAttribute::new(1), -5).shield_max(),
+        0
+    );
+}
+
+#[test]
+fn test_add_shield_clamps() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    assert_eq!(shielded.add_shield(40), 40);
+    assert_eq!(shielded.add_shield(40), 10);
+    assert_eq!(shielded.add_shield(-10), 0);
+    assert_eq!(shielded.effective_value(), 150);
+}
+
+#[test]
+fn test_current_value_is_clamped() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    // The field is public, so it can be set out of range directly.
+    shielded.attribute.current = 150;
+    assert_eq!(shielded.current_value(), shielded.attribute.current_value());
+    assert_eq!(shielded.current_value(), 100);
+    assert_eq!(shielded.effective_value(), 100);
+}
+
+#[test]
+fn test_effective_value_with_minimum() {
+    let attribute =
+        IntegerAttribute::new_as_defined(-50, 100, 100).expect("Failed to create attribute");
+    let mut shielded = ShieldedAttribute::new(attribute, 50);
+    shielded.add_shield(30);
+    assert_eq!(shielded.effective_value(), 130);
+
+    // Reaching the minimum takes the effective value less the minimum.
+    shielded.damage(180);
+    assert_eq!(shielded.current_value(), -50);
+}
+
+#[test]
+fn test_damage_soaked_by_shield() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    shielded.add_shield(20);
+    assert_eq!(
+        shielded.damage(15),
+        ShieldedDamage {
+            absorbed: 15,
+            applied: 0
+        }
+    );
+    assert_eq!(shielded.current_value(), 100);
+    assert_eq!(
+        shielded.damage(500),
+        ShieldedDamage {
+            absorbed: 5,
+            applied: 100
+        }
+    );
+    assert_eq!(shielded.current_value(), 0);
+    assert_eq!(shielded.damage(-10), ShieldedDamage::default());
+}
+
+#[test]
+fn test_operators() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    shielded.add_shield(10);
+    shielded -= 30;
+    assert_eq!(shielded.shield_value(), 0);
+    assert_eq!(shielded.current_value(), 80);
+    shielded += 5;
+    assert_eq!(shielded.current_value(), 85);
+}
+
+#[test]
+fn test_overheal() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    shielded.damage(10);
+    assert_eq!(shielded.overheal(30), 20);
+    assert_eq!(shielded.current_value(), 100);
+    assert_eq!(shielded.shield_value(), 20);
+    assert_eq!(shielded.overheal(100), 30);
+}
+
+#[test]
+fn test_set_shield_max() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50);
+    shielded.add_shield(50);
+    shielded.set_shield_max(20);
+    assert_eq!(shielded.shield_value(), 20);
+    shielded.clear_shield();
+    assert_eq!(shielded.shield_value(), 0);
+}
+
+#[test]
+fn test_decay() {
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50).with_decay(4.0);
+    shielded.add_shield(10);
+    assert_eq!(shielded.decay(0.1), 0);
+    assert_eq!(shielded.decay(0.2), 1);
+    assert_eq!(shielded.decay(100.0), 9);
+    assert_eq!(shielded.shield_value(), 0);
+    assert_eq!(shielded.current_value(), 100);
+}
+
+#[test]
+fn test_decay_system() {
+    let mut world = World::new();
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(100), 50).with_decay(5.0);
+    shielded.add_shield(20);
+    let entity = world.spawn(shielded).id();
+
+    world.run_system_once_with(2.0, decay_shields);
+
+    let shielded = world
+        .get::<ShieldedAttribute>(entity)
+        .expect("Missing ShieldedAttribute");
+    assert_eq!(shielded.shield_value(), 10);
+}
@@ -0,0 +1,40 @@
+//! Tests for the `test_util` strategies.
+#![cfg(feature = "test-util")]
+
+use nwest_shared_component_library::{
+    test_util::integer_attribute_within, FixedDecimalAttribute, FloatAttribute, IntegerAttribute,
+    Percent,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_integer_attributes_are_valid(attribute in any::<IntegerAttribute>()) {
+        prop_assert!(attribute.min <= attribute.max);
+        prop_assert!((attribute.min..=attribute.max).contains(&attribute.current));
+    }
+
+    #[test]
+    fn test_integer_attributes_within_bounds(attribute in integer_attribute_within(-10, 10)) {
+        prop_assert!(attribute.min >= -10);
+        prop_assert!(attribute.max <= 10);
+        prop_assert!(attribute.min <= attribute.current && attribute.current <= attribute.max);
+    }
+
+    #[test]
+    fn test_fixed_decimal_attributes_are_valid(attribute in any::<FixedDecimalAttribute<2>>()) {
+        prop_assert!(attribute.min <= attribute.max);
+        prop_assert!((attribute.min..=attribute.max).contains(&attribute.current));
+    }
+
+    #[test]
+    fn test_float_attributes_are_valid(attribute in any::<FloatAttribute>()) {
+        prop_assert!(attribute.min <= attribute.max);
+        prop_assert!((attribute.min..=attribute.max).contains(&attribute.current));
+    }
+
+    #[test]
+    fn test_percents_are_valid(percent in any::<Percent>()) {
+        prop_assert!(Percent::new(percent.fraction()).is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+//! Tests for `StatTransaction`.
+
+use nwest_shared_component_library::{
+    transaction::StatTransaction, AttributeError, FixedDecimalAttribute, IntegerAttribute,
+};
+
+#[test]
+fn test_commit_applies_all() {
+    let mut mana = IntegerAttribute::new(50);
+    let mut experience = IntegerAttribute::new(100);
+    experience.set_value(0);
+    let mut iron = FixedDecimalAttribute::<3>::new(5_000);
+
+    let transaction = StatTransaction::new()
+        .spend("mana", &mut mana, 20)
+        .add("experience", &mut experience, 15)
+        .spend_units("iron", &mut iron, 1_250);
+    assert_eq!(transaction.len(), 3);
+    transaction.commit().expect("Failed to commit");
+
+    assert_eq!(mana, 30);
+    assert_eq!(experience, 15);
+    assert_eq!(iron, 3_750);
+}
+
+#[test]
+fn test_failure_changes_nothing() {
+    let mut mana = IntegerAttribute::new(50);
+    let mut durability = IntegerAttribute::new(1);
+    let mut iron = FixedDecimalAttribute::<3>::new(5_000);
+
+    let result = StatTransaction::new()
+        .spend("mana", &mut mana, 20)
+        .spend_units("iron", &mut iron, 1_000)
+        .spend("durability", &mut durability, 2)
+        .commit();
+
+    assert_eq!(
+        result,
+        Err(AttributeError::TransactionFailed(
+            "durability".to_string(),
+            Box::new(AttributeError::OutOfBounds(-1, 0, 1))
+        ))
+    );
+    assert_eq!(mana, 50);
+    assert_eq!(iron, 5_000);
+    assert_eq!(durability, 1);
+}
+
+#[test]
+fn test_reports_first_failure() {
+    let mut mana = IntegerAttribute::new(5);
+    let mut stamina = IntegerAttribute::new(5);
+    let result = StatTransaction::new()
+        .spend("mana", &mut mana, 10)
+        .spend("stamina", &mut stamina, 10)
+        .validate();
+    assert!(matches!(result, Err(AttributeError::TransactionFailed(label, _)) if label == "mana"));
+}
+
+#[test]
+fn test_adds_are_not_clamped() {
+    let mut health = IntegerAttribute::new(100);
+    let result = StatTransaction::new()
+        .add("health", &mut health, 1)
+        .commit();
+    assert!(result.is_err());
+    assert_eq!(health, 100);
+}
+
+#[test]
+fn test_extreme_amounts() {
+    let mut health = IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, 0)
+        .expect("Failed to create attribute");
+    let mut gold = FixedDecimalAttribute::<3>::new_as_defined(i64::MIN, i64::MAX, 0)
+        .expect("Failed to create attribute");
+    StatTransaction::new()
+        .spend("health", &mut health, i32::MIN + 1)
+        .spend_units("gold", &mut gold, i64::MIN + 1)
+        .commit()
+        .expect("Failed to commit");
+    assert_eq!(health, i32::MAX);
+    assert_eq!(gold, i64::MAX);
+
+    let result = StatTransaction::new()
+        .spend("health", &mut health, -1)
+        .commit();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_empty() {
+    let transaction = StatTransaction::new();
+    assert!(transaction.is_empty());
+    assert_eq!(transaction.commit(), Ok(()));
+}
+
+#[test]
+fn test_error_message() {
+    let error = AttributeError::TransactionFailed(
+        "mana".to_string(),
+        Box::new(AttributeError::OutOfBounds(-5, 0, 10)),
+    );
+    assert_eq!(
+        error.to_string(),
+        "Transaction failed at 'mana'. Out of bounds. -5 is not between 0 and 10"
+    );
+}
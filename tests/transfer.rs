@@ -0,0 +1,286 @@
+//! Tests for `transfer`, `transfer_units`, and the conversions between attributes.
+
+use nwest_shared_component_library::{
+    transfer::{
+        convert_between, convert_units_between, transfer, transfer_units, TransferPolicy,
+        Transferred,
+    },
+    wallet::ExchangeRate,
+    AttributeError, FixedDecimalAttribute, IntegerAttribute,
+};
+
+const fn attribute(current: i32) -> IntegerAttribute {
+    let mut attribute = IntegerAttribute::new(100);
+    attribute.set_value(current);
+    attribute
+}
+
+#[test]
+fn test_clamp_limited_by_source() {
+    let (mut from, mut to) = (attribute(5), attribute(0));
+    let moved = transfer(&mut from, &mut to, 20, TransferPolicy::Clamp).expect("Failed");
+    assert_eq!(
+        moved,
+        Transferred {
+            taken: 5,
+            received: 5
+        }
+    );
+    assert_eq!(from, 0);
+    assert_eq!(to, 5);
+}
+
+#[test]
+fn test_clamp_limited_by_destination() {
+    let (mut from, mut to) = (attribute(50), attribute(95));
+    let moved = transfer(&mut from, &mut to, 20, TransferPolicy::Clamp).expect("Failed");
+    assert_eq!(
+        moved,
+        Transferred {
+            taken: 5,
+            received: 5
+        }
+    );
+    assert_eq!(from, 45);
+    assert_eq!(to, 100);
+}
+
+#[test]
+fn test_drain_wastes_excess() {
+    let (mut from, mut to) = (attribute(50), attribute(95));
+    let moved = transfer(&mut from, &mut to, 20, TransferPolicy::Drain).expect("Failed");
+    assert_eq!(
+        moved,
+        Transferred {
+            taken: 20,
+            received: 5
+        }
+    );
+    assert_eq!(from, 30);
+    assert_eq!(to, 100);
+}
+
+#[test]
+fn test_exact_is_all_or_nothing() {
+    let (mut from, mut to) = (attribute(10), attribute(50));
+    assert_eq!(
+        transfer(&mut from, &mut to, 20, TransferPolicy::Exact),
+        Err(AttributeError::OutOfBounds(-10, 0, 100))
+    );
+    let (mut from, mut to) = (attribute(50), attribute(90));
+    assert_eq!(
+        transfer(&mut from, &mut to, 20, TransferPolicy::Exact),
+        Err(AttributeError::OutOfBounds(110, 0, 100))
+    );
+    assert_eq!(from, 50);
+    assert_eq!(to, 90);
+    assert!(transfer(&mut from, &mut to, 10, TransferPolicy::Exact).is_ok());
+    assert_eq!(to, 100);
+}
+
+#[test]
+fn test_negative_amount_moves_nothing() {
+    let (mut from, mut to) = (attribute(50), attribute(50));
+    let moved = transfer(&mut from, &mut to, -10, TransferPolicy::Clamp).expect("Failed");
+    assert_eq!(moved, Transferred::default());
+    assert_eq!(from, 50);
+    assert_eq!(to, 50);
+}
+
+#[test]
+fn test_conservation() {
+    for policy in [
+        TransferPolicy::Clamp,
+        TransferPolicy::Drain,
+        TransferPolicy::Exact,
+    ] {
+        for (source, destination, amount) in [(0, 0, 10), (30, 80, 25), (100, 0, 100), (7, 99, 3)] {
+            let (mut from, mut to) = (attribute(source), attribute(destination));
+            let before = from.current_value() + to.current_value();
+            if let Ok(moved) = transfer(&mut from, &mut to, amount, policy) {
+                assert!(moved.received <= moved.taken);
+                assert_eq!(
+                    from.current_value() + to.current_value(),
+                    before - moved.taken + moved.received
+                );
+            }
+            assert!(from.current_value() + to.current_value() <= before);
+        }
+    }
+}
+
+#[test]
+fn test_full_range() {
+    let mut from = IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, i32::MAX)
+        .expect("Failed to create attribute");
+    let mut to = IntegerAttribute::new_as_defined(i32::MIN, i32::MAX, i32::MIN)
+        .expect("Failed to create attribute");
+    let moved = transfer(&mut from, &mut to, i32::MAX, TransferPolicy::Clamp).expect("Failed");
+    assert_eq!(moved.taken, i32::MAX);
+    assert_eq!(from, 0);
+    assert_eq!(to, -1);
+}
+
+#[test]
+fn test_units() {
+    let mut battery = FixedDecimalAttribute::<3>::new(10_000);
+    let mut mana = FixedDecimalAttribute::<3>::new(5_000);
+    mana.set_value(4_250);
+    let moved =
+        transfer_units(&mut battery, &mut mana, 1_000, TransferPolicy::Clamp).expect("Failed");
+    assert_eq!(
+        moved,
+        Transferred {
+            taken: 750,
+            received: 750
+        }
+    );
+    assert_eq!(battery, 9_250);
+    assert_eq!(mana, 5_000);
+}
+
+fn rate(numerator: i64, denominator: i64) -> ExchangeRate {
+    ExchangeRate::new(numerator, denominator).expect("Invalid rate")
+}
+
+#[test]
+fn test_convert_clamp_keeps_the_remainder() {
+    let (mut health, mut mana) = (attribute(50), attribute(0));
+    let converted = convert_between(&mut health, &mut mana, rate(1, 2), 9, TransferPolicy::Clamp)
+        .expect("Failed");
+    assert_eq!(
+        converted,
+        Transferred {
+            taken: 8,
+            received: 4
+        }
+    );
+    assert_eq!(health, 42);
+    assert_eq!(mana, 4);
+}
+
+#[test]
+fn test_convert_clamp_limited_by_destination() {
+    let (mut health, mut mana) = (attribute(100), attribute(95));
+    let converted = convert_between(
+        &mut health,
+        &mut mana,
+        rate(1, 2),
+        40,
+        TransferPolicy::Clamp,
+    )
+    .expect("Failed");
+    assert_eq!(
+        converted,
+        Transferred {
+            taken: 10,
+            received: 5
+        }
+    );
+    assert_eq!(health, 90);
+    assert_eq!(mana, 100);
+}
+
+#[test]
+fn test_convert_drain_and_exact() {
+    let (mut health, mut mana) = (attribute(100), attribute(95));
+    let drained = convert_between(
+        &mut health,
+        &mut mana,
+        rate(1, 2),
+        41,
+        TransferPolicy::Drain,
+    )
+    .expect("Failed");
+    assert_eq!(
+        drained,
+        Transferred {
+            taken: 41,
+            received: 5
+        }
+    );
+    assert_eq!(health, 59);
+
+    let (mut health, mut mana) = (attribute(100), attribute(95));
+    assert!(matches!(
+        convert_between(
+            &mut health,
+            &mut mana,
+            rate(1, 2),
+            40,
+            TransferPolicy::Exact
+        ),
+        Err(AttributeError::OutOfBounds(115, 0, 100))
+    ));
+    assert_eq!(health, 100);
+    assert_eq!(mana, 95);
+
+    let exact = convert_between(&mut health, &mut mana, rate(1, 2), 9, TransferPolicy::Exact)
+        .expect("Failed");
+    assert_eq!(
+        exact,
+        Transferred {
+            taken: 9,
+            received: 4
+        }
+    );
+}
+
+#[test]
+fn test_convert_upwards() {
+    let (mut gold, mut silver) = (attribute(5), attribute(0));
+    let converted = convert_between(
+        &mut gold,
+        &mut silver,
+        rate(30, 1),
+        3,
+        TransferPolicy::Clamp,
+    )
+    .expect("Failed");
+    assert_eq!(
+        converted,
+        Transferred {
+            taken: 3,
+            received: 90
+        }
+    );
+    // Only 10 more silver fits, and a whole gold is worth 30, so nothing converts.
+    let converted = convert_between(
+        &mut gold,
+        &mut silver,
+        rate(30, 1),
+        5,
+        TransferPolicy::Clamp,
+    );
+    assert_eq!(
+        converted,
+        Ok(Transferred {
+            taken: 0,
+            received: 0
+        })
+    );
+}
+
+#[test]
+fn test_convert_units() {
+    let mut essence = FixedDecimalAttribute::<2>::new(1_000);
+    let mut mana = FixedDecimalAttribute::<3>::new(100_000);
+    mana.set_value(0);
+    let converted = convert_units_between(
+        &mut essence,
+        &mut mana,
+        rate(10, 1),
+        250,
+        TransferPolicy::Clamp,
+    )
+    .expect("Failed");
+    assert_eq!(
+        converted,
+        Transferred {
+            taken: 250,
+            received: 2_500
+        }
+    );
+    assert_eq!(essence, 750);
+    assert_eq!(mana, 2_500);
+}
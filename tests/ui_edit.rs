@@ -0,0 +1,153 @@
+//! Tests for applying UI edits to attributes.
+
+use nwest_shared_component_library::{
+    ui_edit::{AttributeField, EditPolicy, FieldChange, UiEdit},
+    FixedDecimalAttribute, FloatAttribute, IntegerAttribute,
+};
+
+fn attribute() -> IntegerAttribute {
+    IntegerAttribute::new_as_defined(0, 100, 50).expect("Failed to create attribute")
+}
+
+const fn change(field: AttributeField, old: i32, new: i32) -> FieldChange<i32> {
+    FieldChange { field, old, new }
+}
+
+#[test]
+fn test_edit_within_range() {
+    for policy in [EditPolicy::Adjust, EditPolicy::Clamp, EditPolicy::Reject] {
+        let mut attribute = attribute();
+        assert_eq!(
+            attribute.apply_ui_edit(AttributeField::Current, 75, policy),
+            Ok(vec![change(AttributeField::Current, 50, 75)])
+        );
+        assert_eq!(
+            attribute.apply_ui_edit(AttributeField::Current, 75, policy),
+            Ok(vec![])
+        );
+    }
+}
+
+#[test]
+fn test_min_edits() {
+    let mut adjusted = attribute();
+    assert_eq!(
+        adjusted.apply_ui_edit(AttributeField::Min, 150, EditPolicy::Adjust),
+        Ok(vec![
+            change(AttributeField::Min, 0, 150),
+            change(AttributeField::Max, 100, 150),
+            change(AttributeField::Current, 50, 150),
+        ])
+    );
+
+    let mut clamped = attribute();
+    assert_eq!(
+        clamped.apply_ui_edit(AttributeField::Min, 150, EditPolicy::Clamp),
+        Ok(vec![
+            change(AttributeField::Min, 0, 100),
+            change(AttributeField::Current, 50, 100),
+        ])
+    );
+
+    let mut rejected = attribute();
+    assert!(rejected
+        .apply_ui_edit(AttributeField::Min, 150, EditPolicy::Reject)
+        .is_err());
+    assert_eq!(rejected, attribute());
+    assert_eq!(
+        rejected.apply_ui_edit(AttributeField::Min, 60, EditPolicy::Reject),
+        Ok(vec![
+            change(AttributeField::Min, 0, 60),
+            change(AttributeField::Current, 50, 60),
+        ])
+    );
+}
+
+#[test]
+fn test_max_edits() {
+    let mut adjusted = attribute();
+    assert_eq!(
+        adjusted.apply_ui_edit(AttributeField::Max, -10, EditPolicy::Adjust),
+        Ok(vec![
+            change(AttributeField::Min, 0, -10),
+            change(AttributeField::Max, 100, -10),
+            change(AttributeField::Current, 50, -10),
+        ])
+    );
+
+    let mut clamped = attribute();
+    assert_eq!(
+        clamped.apply_ui_edit(AttributeField::Max, -10, EditPolicy::Clamp),
+        Ok(vec![
+            change(AttributeField::Max, 100, 0),
+            change(AttributeField::Current, 50, 0),
+        ])
+    );
+
+    let mut rejected = attribute();
+    assert!(rejected
+        .apply_ui_edit(AttributeField::Max, -10, EditPolicy::Reject)
+        .is_err());
+    assert_eq!(rejected, attribute());
+}
+
+#[test]
+fn test_current_edits() {
+    let mut adjusted = attribute();
+    assert_eq!(
+        adjusted.apply_ui_edit(AttributeField::Current, 120, EditPolicy::Adjust),
+        Ok(vec![
+            change(AttributeField::Max, 100, 120),
+            change(AttributeField::Current, 50, 120),
+        ])
+    );
+
+    let mut clamped = attribute();
+    assert_eq!(
+        clamped.apply_ui_edit(AttributeField::Current, -20, EditPolicy::Clamp),
+        Ok(vec![change(AttributeField::Current, 50, 0)])
+    );
+
+    let mut rejected = attribute();
+    assert!(rejected
+        .apply_ui_edit(AttributeField::Current, 120, EditPolicy::Reject)
+        .is_err());
+    assert_eq!(rejected, attribute());
+}
+
+#[test]
+fn test_fixed_decimal() {
+    let mut gold = FixedDecimalAttribute::<2>::new(10_000);
+    assert_eq!(
+        gold.apply_ui_edit(AttributeField::Max, 5_000, EditPolicy::Adjust),
+        Ok(vec![
+            FieldChange {
+                field: AttributeField::Max,
+                old: 10_000,
+                new: 5_000
+            },
+            FieldChange {
+                field: AttributeField::Current,
+                old: 10_000,
+                new: 5_000
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_float_rejects_nan() {
+    let mut temperature = FloatAttribute::new(40.0);
+    for policy in [EditPolicy::Adjust, EditPolicy::Clamp, EditPolicy::Reject] {
+        assert!(temperature
+            .apply_ui_edit(AttributeField::Current, f32::NAN, policy)
+            .is_err());
+    }
+    assert_eq!(temperature, FloatAttribute::new(40.0));
+
+    let changes = temperature
+        .apply_ui_edit(AttributeField::Min, -10.0, EditPolicy::Adjust)
+        .expect("Failed to edit");
+    assert_eq!(changes.len(), 1);
+    assert!((temperature.min + 10.0).abs() < f32::EPSILON);
+}
@@ -0,0 +1,64 @@
+//! Tests for the vitals components and `VitalsBundle`.
+
+use bevy_ecs::{
+    system::{Query, RunSystemOnce},
+    world::World,
+};
+use nwest_shared_component_library::{Health, IntegerAttribute, Mana, Stamina, VitalsBundle};
+
+#[test]
+fn test_new() {
+    let vitals = VitalsBundle::new(100, 50, 75);
+    assert_eq!(vitals.health.current_value(), 100);
+    assert_eq!(vitals.mana.max, 50);
+    assert_eq!(vitals.stamina.0, IntegerAttribute::new(75));
+}
+
+#[test]
+fn test_deref_mut() {
+    let mut mana = Mana::new(50);
+    *mana -= 60;
+    assert_eq!(mana.current_value(), 0);
+    mana.set_value(20);
+    assert_eq!(**mana, 20);
+    assert_eq!(IntegerAttribute::from(mana).current_value(), 20);
+}
+
+#[test]
+fn test_serializes_as_attribute() {
+    let health = Health::new(10);
+    assert_eq!(
+        serde_json::to_string(&health).expect("Failed to serialize"),
+        serde_json::to_string(&health.0).expect("Failed to serialize")
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn drain_stamina(mut query: Query<&mut Stamina>) {
+    for mut stamina in &mut query {
+        *stamina -= 25;
+    }
+}
+
+#[test]
+fn test_spawn_and_query() {
+    let mut world = World::new();
+    let entity = world.spawn(VitalsBundle::new(100, 50, 75)).id();
+
+    world.run_system_once(drain_stamina);
+
+    assert_eq!(
+        world
+            .get::<Stamina>(entity)
+            .expect("Missing stamina")
+            .current_value(),
+        50
+    );
+    assert_eq!(
+        world
+            .get::<Health>(entity)
+            .expect("Missing health")
+            .current_value(),
+        100
+    );
+}
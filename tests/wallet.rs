@@ -0,0 +1,156 @@
+//! Tests for the `Wallet` component.
+
+use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    wallet::{emit_wallet_transactions, ExchangeRate},
+    AttributeError, FixedDecimalAttribute, Transaction, Wallet, WalletTransaction,
+};
+
+fn wallet() -> Wallet {
+    let mut gold = FixedDecimalAttribute::new(10_000);
+    gold.set_value(1_000);
+    let mut silver = FixedDecimalAttribute::new(50_000);
+    silver.set_value(0);
+    Wallet::new()
+        .with_currency("gold", gold)
+        .with_currency("silver", silver)
+}
+
+#[test]
+fn test_balance() {
+    let wallet = wallet();
+    assert_eq!(wallet.balance("gold"), Some(1_000));
+    assert_eq!(wallet.balance("gems"), None);
+    assert_eq!(
+        wallet
+            .currencies()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        vec!["gold", "silver"]
+    );
+}
+
+#[test]
+fn test_deposit_clamps() {
+    let mut wallet = wallet();
+    assert_eq!(wallet.deposit("gold", 20_000), Ok(9_000));
+    assert_eq!(wallet.balance("gold"), Some(10_000));
+}
+
+#[test]
+fn test_try_spend() {
+    let mut wallet = wallet();
+    assert!(wallet.can_afford("gold", 1_000));
+    assert!(!wallet.can_afford("gold", 1_001));
+    assert_eq!(wallet.try_spend("gold", 400), Ok(()));
+    assert_eq!(
+        wallet.try_spend("gold", 700),
+        Err(AttributeError::InsufficientFunds(
+            "gold".to_string(),
+            700,
+            600
+        ))
+    );
+    assert_eq!(wallet.balance("gold"), Some(600));
+}
+
+#[test]
+fn test_unknown_currency() {
+    let mut wallet = wallet();
+    assert_eq!(
+        wallet.deposit("gems", 1),
+        Err(AttributeError::UnknownCurrency("gems".to_string()))
+    );
+    assert!(!wallet.can_afford("gems", 0));
+}
+
+#[test]
+fn test_negative_amounts() {
+    let mut wallet = wallet();
+    assert!(!wallet.can_afford("gold", -1));
+    assert!(wallet.try_spend("gold", -100).is_err());
+    assert!(wallet.deposit("gold", -100).is_err());
+    assert_eq!(wallet.balance("gold"), Some(1_000));
+}
+
+#[test]
+fn test_exchange_rate() {
+    assert!(ExchangeRate::new(0, 1).is_err());
+    let rate = ExchangeRate::new(3, 2).expect("Invalid rate");
+    assert_eq!(rate.convert(5), 7);
+    assert_eq!(rate.convert(i64::MAX), i64::MAX);
+    assert_eq!(rate.cost(7), 5);
+    assert_eq!(rate.cost(6), 4);
+    assert_eq!(rate.cost(0), 0);
+    assert_eq!(rate.cost(i64::MAX), 6_148_914_691_236_517_205);
+
+    // Costing more than an `i64` can hold saturates.
+    let halving = ExchangeRate::new(1, 2).expect("Invalid rate");
+    assert_eq!(halving.cost(i64::MAX), i64::MAX);
+}
+
+#[test]
+fn test_exchange() {
+    let mut wallet = wallet();
+    let gold_to_silver = ExchangeRate::new(100, 1).expect("Invalid rate");
+    assert_eq!(
+        wallet.exchange("gold", "silver", 300, gold_to_silver),
+        Ok(30_000)
+    );
+    assert_eq!(wallet.balance("gold"), Some(700));
+    assert_eq!(wallet.balance("silver"), Some(30_000));
+
+    // Not enough room for another 30000 silver, so nothing changes.
+    assert_eq!(
+        wallet.exchange("gold", "silver", 300, gold_to_silver),
+        Err(AttributeError::InsufficientCapacity(
+            "silver".to_string(),
+            30_000,
+            20_000
+        ))
+    );
+    assert_eq!(wallet.balance("gold"), Some(700));
+}
+
+#[test]
+fn test_transactions() {
+    let mut wallet = wallet();
+    wallet.try_spend("gold", 100).expect("Failed to spend");
+    wallet.deposit("silver", 0).expect("Failed to deposit");
+    wallet.deposit("silver", 5).expect("Failed to deposit");
+    assert_eq!(
+        wallet.drain_transactions(),
+        vec![
+            Transaction {
+                currency: "gold".to_string(),
+                amount: -100,
+                balance: 900
+            },
+            Transaction {
+                currency: "silver".to_string(),
+                amount: 5,
+                balance: 5
+            },
+        ]
+    );
+    assert!(wallet.drain_transactions().is_empty());
+}
+
+#[test]
+fn test_system_emits_events() {
+    let mut world = World::new();
+    world.init_resource::<Events<WalletTransaction>>();
+    let mut purse = wallet();
+    purse.deposit("gold", 10).expect("Failed to deposit");
+    let entity = world.spawn(purse).id();
+
+    world.run_system_once(emit_wallet_transactions);
+
+    let events: Vec<WalletTransaction> = world
+        .resource_mut::<Events<WalletTransaction>>()
+        .drain()
+        .collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, entity);
+    assert_eq!(events[0].transaction.amount, 10);
+}
@@ -0,0 +1,56 @@
+//! Tests for the `Watermarks` component.
+
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use nwest_shared_component_library::{
+    watermarks::{update_watermarks, Watermarks},
+    IntegerAttribute,
+};
+
+#[test]
+fn test_observe() {
+    let mut mana = IntegerAttribute::new(50);
+    mana.set_value(20);
+    let mut watermarks = Watermarks::new(&mana);
+    assert_eq!(watermarks.low_watermark(), 20);
+    assert_eq!(watermarks.high_watermark(), 20);
+
+    mana += 25;
+    watermarks.observe(&mana);
+    mana -= 40;
+    watermarks.observe(&mana);
+    mana += 10;
+    watermarks.observe(&mana);
+
+    assert_eq!(watermarks.low_watermark(), 5);
+    assert_eq!(watermarks.high_watermark(), 45);
+}
+
+#[test]
+fn test_reset() {
+    let mut health = IntegerAttribute::new(100);
+    let mut watermarks = Watermarks::new(&health);
+    health -= 90;
+    watermarks.observe(&health);
+    health += 30;
+    watermarks.reset_watermarks(&health);
+    assert_eq!(watermarks.low_watermark(), 40);
+    assert_eq!(watermarks.high_watermark(), 40);
+}
+
+#[test]
+fn test_system() {
+    let mut world = World::new();
+    let health = IntegerAttribute::new(100);
+    let entity = world.spawn((health, Watermarks::new(&health))).id();
+
+    for damage in [30, 69, -50] {
+        *world
+            .get_mut::<IntegerAttribute>(entity)
+            .expect("Missing attribute") -= damage;
+        world.run_system_once(update_watermarks::<IntegerAttribute>);
+    }
+
+    let watermarks = world.get::<Watermarks>(entity).expect("Missing watermarks");
+    assert_eq!(watermarks.low_watermark(), 1);
+    assert_eq!(watermarks.high_watermark(), 100);
+}
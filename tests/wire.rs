@@ -0,0 +1,137 @@
+//! Tests for the `WireFormat` binary encoding.
+
+use nwest_shared_component_library::{
+    wire::WireFormat, AttributeError, DownedState, FixedDecimalAttribute, FloatAttribute,
+    IntegerAttribute, LifeState,
+};
+
+#[test]
+fn test_integer_attribute_layout() {
+    let attribute =
+        IntegerAttribute::new_as_defined(-1, 256, 2).expect("Failed to create IntegerAttribute");
+    assert_eq!(
+        attribute.to_bytes(),
+        vec![0xFF, 0xFF, 0xFF, 0xFF, 0, 1, 0, 0, 2, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_integer_attribute_round_trip() {
+    let attribute =
+        IntegerAttribute::new_as_defined(-50, 50, 10).expect("Failed to create IntegerAttribute");
+    let decoded = IntegerAttribute::from_bytes(&attribute.to_bytes()).expect("Failed to decode");
+    assert_eq!(decoded.min, -50);
+    assert_eq!(decoded.max, 50);
+    assert_eq!(decoded.current, 10);
+}
+
+#[test]
+fn test_fixed_decimal_round_trip() {
+    let attribute = FixedDecimalAttribute::<2>::new_as_defined(0, 1_000_000, 12_345)
+        .expect("Failed to create FixedDecimalAttribute");
+    let bytes = attribute.to_bytes();
+    assert_eq!(bytes.len(), 24);
+    let decoded = FixedDecimalAttribute::<2>::from_bytes(&bytes).expect("Failed to decode");
+    assert_eq!(decoded.max, 1_000_000);
+    assert_eq!(decoded, 12_345);
+}
+
+#[test]
+fn test_downed_state_round_trip() {
+    let mut state = DownedState::new(8.0, 25);
+    state.state = LifeState::Downed { remaining: 3.5 };
+    let bytes = state.to_bytes();
+    assert_eq!(bytes.len(), DownedState::WIRE_SIZE);
+    assert_eq!(bytes[0], 1);
+    assert_eq!(DownedState::from_bytes(&bytes), Ok(state));
+}
+
+#[test]
+fn test_rejects_wrong_length() {
+    assert_eq!(
+        IntegerAttribute::from_bytes(&[0; 11]),
+        Err(AttributeError::ConversionError(
+            "Expected 12 bytes but got 11.".to_string()
+        ))
+    );
+    assert!(IntegerAttribute::read_bytes(&[0; 4]).is_err());
+}
+
+#[test]
+fn test_rejects_invalid_values() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&10_i32.to_le_bytes());
+    bytes.extend_from_slice(&0_i32.to_le_bytes());
+    bytes.extend_from_slice(&5_i32.to_le_bytes());
+    assert_eq!(
+        IntegerAttribute::from_bytes(&bytes),
+        Err(AttributeError::MinGreaterThanMax(10, 0))
+    );
+    assert!(LifeState::from_bytes(&[9, 0, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_read_bytes_from_larger_buffer() {
+    let mut buffer = Vec::new();
+    IntegerAttribute::new(10).write_bytes(&mut buffer);
+    IntegerAttribute::new(20).write_bytes(&mut buffer);
+    let second = IntegerAttribute::read_bytes(&buffer[IntegerAttribute::WIRE_SIZE..])
+        .expect("Failed to decode");
+    assert_eq!(second, 20);
+}
+
+#[test]
+fn test_float_attribute_round_trip() {
+    let attribute =
+        FloatAttribute::new_as_defined(-1.5, 8.0, 2.25).expect("Failed to create FloatAttribute");
+    let bytes = attribute.to_bytes();
+    assert_eq!(bytes.len(), 12);
+    let decoded = FloatAttribute::from_bytes(&bytes).expect("Failed to decode");
+    assert!((decoded.min + 1.5).abs() < f32::EPSILON);
+    assert!((decoded.max - 8.0).abs() < f32::EPSILON);
+    assert_eq!(decoded, 2.25);
+
+    let mut nan = bytes;
+    nan[8..12].copy_from_slice(&f32::NAN.to_le_bytes());
+    assert!(FloatAttribute::from_bytes(&nan).is_err());
+}
+
+#[test]
+fn test_rejects_current_out_of_range() {
+    let mut bytes = IntegerAttribute::new(100).to_bytes();
+    bytes[8..12].copy_from_slice(&101_i32.to_le_bytes());
+    assert_eq!(
+        IntegerAttribute::from_bytes(&bytes),
+        Err(AttributeError::OutOfBounds(101, 0, 100))
+    );
+
+    let mut bytes = FixedDecimalAttribute::<2>::new(100).to_bytes();
+    bytes[16..24].copy_from_slice(&(-1_i64).to_le_bytes());
+    assert_eq!(
+        FixedDecimalAttribute::<2>::from_bytes(&bytes),
+        Err(AttributeError::OutOfBounds(-1, 0, 100))
+    );
+
+    let mut bytes = FloatAttribute::new(1.0).to_bytes();
+    bytes[8..12].copy_from_slice(&1.5_f32.to_le_bytes());
+    assert!(FloatAttribute::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_rejects_invalid_bleed_out_time() {
+    for remaining in [f32::NAN, f32::INFINITY, -1.0] {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&remaining.to_le_bytes());
+        assert!(LifeState::from_bytes(&bytes).is_err());
+    }
+}
+
+#[test]
+fn test_rejects_invalid_bleed_out_duration() {
+    for duration in [f32::NAN, f32::NEG_INFINITY, -0.5] {
+        let mut bytes = DownedState::new(8.0, 25).to_bytes();
+        let offset = LifeState::WIRE_SIZE;
+        bytes[offset..offset + 4].copy_from_slice(&duration.to_le_bytes());
+        assert!(DownedState::from_bytes(&bytes).is_err());
+    }
+}
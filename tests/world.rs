@@ -0,0 +1,268 @@
+//! End-to-end tests running the library's components and systems in a `World`.
+#![cfg(feature = "test-util")]
+
+use nwest_shared_component_library::{
+    markers::{AttributeEmpty, AttributeFull},
+    periodic_reset::ResetTarget,
+    test_harness::TestHarness,
+    AttributeChangeTracker, AttributeChanged, AttributeDepleted, AttributeHistory, Depletable,
+    Depleted, DepletionPolicy, DownedEvent, DownedState, DownedTransition, FixedDecimalAttribute,
+    Health, IntegerAttribute, Mana, PeriodicReset, Regeneration, Reputation, ReputationTierChanged,
+    ReviveRequest, ShieldedAttribute, VitalsBundle, Wallet, WalletTransaction,
+};
+
+#[test]
+fn test_regeneration_fills_and_marks_full() {
+    let mut harness = TestHarness::new(0.25);
+    let mut mana = IntegerAttribute::new(10);
+    mana.set_value(7);
+    let entity = harness
+        .world_mut()
+        .spawn((
+            mana,
+            Regeneration::new(2.0, 0.0),
+            AttributeChangeTracker::new(),
+        ))
+        .id();
+
+    harness.tick();
+    assert!(!harness.world().entity(entity).contains::<AttributeFull>());
+
+    harness.run_ticks(7);
+    assert_eq!(
+        harness
+            .get::<IntegerAttribute>(entity)
+            .map(|mana| mana.current),
+        Some(10)
+    );
+    assert!(harness.world().entity(entity).contains::<AttributeFull>());
+
+    let values: Vec<i32> = harness
+        .drain_events::<AttributeChanged>()
+        .into_iter()
+        .map(|change| change.new)
+        .collect();
+    assert_eq!(values, vec![8, 9, 10]);
+}
+
+#[test]
+fn test_downed_revive_cycle() {
+    let mut harness = TestHarness::new(0.5);
+    let entity = harness
+        .world_mut()
+        .spawn((IntegerAttribute::new(100) - 100, DownedState::new(2.0, 25)))
+        .id();
+
+    harness.run_ticks(2);
+    assert!(harness.world().entity(entity).contains::<AttributeEmpty>());
+    harness.send(ReviveRequest { entity });
+    harness.tick();
+
+    assert_eq!(
+        harness
+            .drain_events::<DownedEvent>()
+            .into_iter()
+            .map(|event| event.transition)
+            .collect::<Vec<_>>(),
+        vec![DownedTransition::Downed, DownedTransition::Revived]
+    );
+    assert_eq!(
+        harness
+            .get::<IntegerAttribute>(entity)
+            .map(|health| health.current),
+        Some(25)
+    );
+    assert!(!harness.world().entity(entity).contains::<AttributeEmpty>());
+}
+
+#[test]
+fn test_bleed_out_without_revive() {
+    let mut harness = TestHarness::new(1.0);
+    let entity = harness
+        .world_mut()
+        .spawn((IntegerAttribute::new(10) - 10, DownedState::new(3.0, 5)))
+        .id();
+
+    harness.run_ticks(5);
+    assert!(harness
+        .get::<DownedState>(entity)
+        .is_some_and(DownedState::is_dead));
+}
+
+#[test]
+fn test_shield_decays_over_ticks() {
+    let mut harness = TestHarness::new(0.1);
+    let mut shielded = ShieldedAttribute::new(IntegerAttribute::new(50), 20).with_decay(10.0);
+    shielded.add_shield(20);
+    let entity = harness.world_mut().spawn(shielded).id();
+
+    harness.run_ticks(15);
+    assert_eq!(
+        harness
+            .get::<ShieldedAttribute>(entity)
+            .map(ShieldedAttribute::shield_value),
+        Some(5)
+    );
+}
+
+#[test]
+fn test_wallet_and_reputation_events() {
+    let mut harness = TestHarness::new(1.0);
+    let mut wallet = Wallet::new().with_currency("gold", FixedDecimalAttribute::new(1_000));
+    wallet.try_spend("gold", 100).expect("Failed to spend");
+    let mut reputation = Reputation::default().with_decay(0, 100.0);
+    reputation.set_standing("guards", 550);
+    reputation.drain_tier_changes();
+    let entity = harness.world_mut().spawn((wallet, reputation)).id();
+
+    harness.tick();
+
+    let transactions = harness.drain_events::<WalletTransaction>();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].entity, entity);
+    let changes = harness.drain_events::<ReputationTierChanged>();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].entity, entity);
+
+    harness.run_ticks(3);
+    assert!(harness.drain_events::<WalletTransaction>().is_empty());
+    assert_eq!(
+        harness
+            .get::<Reputation>(entity)
+            .map(|reputation| reputation.standing("guards")),
+        Some(150)
+    );
+}
+
+#[test]
+fn test_revive_clears_locked_depletion() {
+    let mut harness = TestHarness::new(0.5);
+    let entity = harness
+        .world_mut()
+        .spawn((
+            IntegerAttribute::new(100) - 100,
+            DownedState::new(2.0, 25),
+            Depletable::new(DepletionPolicy::Locked),
+        ))
+        .id();
+
+    harness.tick();
+    assert!(harness.world().entity(entity).contains::<Depleted>());
+    assert_eq!(harness.drain_events::<AttributeDepleted>().len(), 1);
+
+    // The revive runs right before depletion, and must not be undone by it.
+    harness.send(ReviveRequest { entity });
+    harness.tick();
+    assert!(!harness.world().entity(entity).contains::<Depleted>());
+    assert_eq!(
+        harness
+            .get::<IntegerAttribute>(entity)
+            .map(|health| health.current),
+        Some(25)
+    );
+
+    harness.tick();
+    assert_eq!(
+        harness
+            .get::<IntegerAttribute>(entity)
+            .map(|health| health.current),
+        Some(25)
+    );
+    assert!(harness.drain_events::<AttributeDepleted>().is_empty());
+}
+
+#[test]
+fn test_history_records_changes() {
+    let mut harness = TestHarness::new(0.5);
+    let entity = harness
+        .world_mut()
+        .spawn((IntegerAttribute::new(50), AttributeHistory::new(0.5, 10)))
+        .id();
+
+    harness.tick();
+    if let Some(mut health) = harness.world_mut().get_mut::<IntegerAttribute>(entity) {
+        health.set_value(40);
+    }
+    harness.tick();
+
+    assert_eq!(
+        harness
+            .get::<AttributeHistory>(entity)
+            .map(AttributeHistory::samples),
+        Some(vec![50, 40])
+    );
+}
+
+#[test]
+fn test_reset_then_regenerate_in_order() {
+    let mut harness = TestHarness::new(1.0);
+    let entity = harness
+        .world_mut()
+        .spawn((
+            IntegerAttribute::new(10),
+            PeriodicReset::every(1.0, ResetTarget::Value(0)),
+            Regeneration::new(2.0, 0.0),
+        ))
+        .id();
+
+    // Every tick resets to 0 and then regenerates, whatever order the executor would pick.
+    for _ in 0..5 {
+        harness.tick();
+        assert_eq!(
+            harness
+                .get::<IntegerAttribute>(entity)
+                .map(|attribute| attribute.current),
+            Some(2)
+        );
+    }
+}
+
+#[test]
+fn test_vitals_health_regenerates_downs_and_depletes() {
+    let mut harness = TestHarness::new(0.5);
+    let mut vitals = VitalsBundle::new(100, 50, 75);
+    vitals.health -= 10;
+    vitals.mana -= 10;
+    let entity = harness
+        .world_mut()
+        .spawn((
+            vitals,
+            Regeneration::new(4.0, 0.0),
+            DownedState::new(2.0, 25),
+            Depletable::new(DepletionPolicy::Modifiable),
+        ))
+        .id();
+
+    harness.run_ticks(2);
+    assert_eq!(
+        harness.get::<Health>(entity).map(|health| health.current),
+        Some(94)
+    );
+    // The companion components follow the entity's health, not its other pools.
+    assert_eq!(
+        harness.get::<Mana>(entity).map(|mana| mana.current),
+        Some(40)
+    );
+
+    // Regeneration runs before depletion, so it would heal the health back up first.
+    harness
+        .world_mut()
+        .entity_mut(entity)
+        .remove::<Regeneration>();
+    *harness
+        .world_mut()
+        .get_mut::<Health>(entity)
+        .expect("Missing health") -= 100;
+    harness.tick();
+    assert!(harness.world().entity(entity).contains::<Depleted>());
+    assert!(harness.world().entity(entity).contains::<AttributeEmpty>());
+    assert_eq!(harness.drain_events::<AttributeDepleted>().len(), 1);
+    assert_eq!(
+        harness
+            .drain_events::<DownedEvent>()
+            .into_iter()
+            .map(|event| event.transition)
+            .collect::<Vec<_>>(),
+        vec![DownedTransition::Downed]
+    );
+}